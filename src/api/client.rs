@@ -1,14 +1,26 @@
-use crate::api::models::{ApiError, Issue, IssueUpdate, ListIssuesParams};
+use crate::api::models::{
+    ApiError, AssembleBundleRequest, AssembleDifRequest, AssembleDifResponse, AssembleResponse,
+    AuthInfo, CheckIn, CheckInCreated, CheckInUpdate, ChunkUploadOptions, Deploy, Event, Issue,
+    IssueMerge, IssueUpdate, ListIssuesParams, ListReleasesParams, Monitor, NewCheckIn, NewDeploy,
+    NewProject, NewProjectKey, NewRelease, NewTeam, Organization, PreviousRelease, Project,
+    ProjectKey, Release, ReleaseUpdate, Team, TeamMember,
+};
+use crate::api::pagination::Page;
+use crate::api::retry::{parse_retry_after, RetryPolicy};
 use crate::config::Config;
 use crate::error::{Result, SentryCliError};
-use reqwest::{Client, Response, StatusCode};
+use futures::Stream;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use std::collections::BTreeMap;
 use url::Url;
 
 pub struct SentryClient {
-    client: Client,
+    pub(super) client: Client,
     base_url: Url,
     auth_token: String,
-    org_slug: String,
+    org_slug: Option<String>,
+    retry: RetryPolicy,
     verbose: bool,
 }
 
@@ -18,17 +30,21 @@ impl SentryClient {
         org_override: Option<&str>,
         server_override: Option<&str>,
         token_override: Option<&str>,
+        retries_override: Option<u32>,
         verbose: bool,
     ) -> Result<Self> {
-        let auth_token = config.get_auth_token(token_override)?;
-        let base_url_str = config.get_server_url(server_override);
-        let org_slug = config.get_org(org_override)?;
+        let (auth_token, token_source) = config.resolve_auth_token(token_override)?;
+        let base_url_str = config.get_server_url(server_override, &token_source);
+        let org_slug = config.get_org(org_override);
+        let max_retries = config.get_max_retries(retries_override)?;
 
         let base_url = Url::parse(&base_url_str)?;
 
         if verbose {
             eprintln!("[verbose] Server: {}", base_url);
-            eprintln!("[verbose] Organization: {}", org_slug);
+            if let Some(org) = &org_slug {
+                eprintln!("[verbose] Organization: {}", org);
+            }
         }
 
         Ok(Self {
@@ -36,16 +52,43 @@ impl SentryClient {
             base_url,
             auth_token,
             org_slug,
+            retry: RetryPolicy::with_max_retries(max_retries),
             verbose,
         })
     }
 
+    /// The organization slug. Resolved lazily so that commands which do not
+    /// need an organization (e.g. listing organizations) work without one.
+    pub fn org(&self) -> Result<&str> {
+        self.org_slug.as_deref().ok_or_else(|| {
+            SentryCliError::Config(
+                "No organization specified. Use --org or configure default_org".into(),
+            )
+        })
+    }
+
+    pub fn server_url(&self) -> &Url {
+        &self.base_url
+    }
+
     fn api_url(&self, path: &str) -> Result<Url> {
         Ok(self.base_url.join(&format!("/api/0/{}", path))?)
     }
 
+    /// URL for a release, with the version percent-encoded as a single path segment
+    fn release_url(&self, version: &str, suffix: &[&str]) -> Result<Url> {
+        let mut url = self.api_url(&format!("organizations/{}/releases/", self.org()?))?;
+        url.path_segments_mut()
+            .map_err(|_| SentryCliError::Validation("Invalid server URL".to_string()))?
+            .pop_if_empty()
+            .push(version)
+            .extend(suffix)
+            .push("");
+        Ok(url)
+    }
+
     fn build_issues_url(&self, params: &ListIssuesParams) -> Result<Url> {
-        let mut url = self.api_url(&format!("organizations/{}/issues/", self.org_slug))?;
+        let mut url = self.api_url(&format!("organizations/{}/issues/", self.org()?))?;
 
         {
             let mut query_pairs = url.query_pairs_mut();
@@ -96,12 +139,76 @@ impl SentryClient {
         }
     }
 
+    /// Send an authenticated request, retrying rate-limited and transient
+    /// failures according to the client's retry policy.
+    ///
+    /// Only idempotent methods are retried. `Retry-After` is honored when the
+    /// server provides it, unless it asks to wait longer than the policy's
+    /// `max_delay`; otherwise exponential backoff with jitter is used.
+    pub(super) async fn send(&self, builder: RequestBuilder) -> Result<Response> {
+        let request = builder.bearer_auth(&self.auth_token).build()?;
+        let retryable = RetryPolicy::is_retryable_method(request.method());
+        let mut retry = 0;
+
+        loop {
+            self.log_request(request.method().as_str(), request.url());
+
+            // Streaming bodies (e.g. multipart uploads) cannot be cloned and
+            // are sent exactly once, as is the final attempt of any request
+            let can_retry = retryable && retry < self.retry.max_retries;
+            let attempt = match request.try_clone() {
+                Some(attempt) if can_retry => attempt,
+                _ => {
+                    let response = self.client.execute(request).await?;
+                    self.log_response(response.status());
+                    return Ok(response);
+                }
+            };
+
+            let delay = match self.client.execute(attempt).await {
+                Ok(response) => {
+                    let status = response.status();
+                    self.log_response(status);
+
+                    if !RetryPolicy::is_retryable_status(status) {
+                        return Ok(response);
+                    }
+
+                    match parse_retry_after(response.headers()) {
+                        // The server asks for a longer wait than we allow; return its
+                        // response (429 or 5xx) as is so the caller reports it
+                        Some(delay) if delay > self.retry.max_delay => return Ok(response),
+                        Some(delay) => delay,
+                        None => self.retry.backoff(retry),
+                    }
+                }
+                Err(e) if RetryPolicy::is_retryable_error(&e) => {
+                    if self.verbose {
+                        eprintln!("[verbose] Request failed: {}", e);
+                    }
+                    self.retry.backoff(retry)
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            retry += 1;
+            if self.verbose {
+                eprintln!(
+                    "[verbose] Retrying in {:.1}s (retry {}/{})",
+                    delay.as_secs_f64(),
+                    retry,
+                    self.retry.max_retries
+                );
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+
     async fn handle_response<T: serde::de::DeserializeOwned>(
         &self,
         response: Response,
     ) -> Result<T> {
         let status = response.status();
-        self.log_response(status);
 
         if status.is_success() {
             Ok(response.json().await?)
@@ -110,13 +217,25 @@ impl SentryClient {
         }
     }
 
-    async fn map_error_response(&self, status: StatusCode, response: Response) -> SentryCliError {
+    /// Check the status of a response whose body is not needed
+    async fn handle_empty_response(&self, response: Response) -> Result<()> {
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(self.map_error_response(status, response).await)
+        }
+    }
+
+    pub(super) async fn map_error_response(
+        &self,
+        status: StatusCode,
+        response: Response,
+    ) -> SentryCliError {
         // Parse Retry-After header for rate limiting
-        let retry_after = response
-            .headers()
-            .get("retry-after")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.parse::<u64>().ok())
+        let retry_after = parse_retry_after(response.headers())
+            .map(|d| d.as_secs())
             .unwrap_or(60);
 
         let error_body = response.text().await.unwrap_or_default();
@@ -136,121 +255,27 @@ impl SentryClient {
         }
     }
 
-    /// Parse the Link header to find the next page cursor
-    fn parse_next_cursor(link_header: Option<&str>) -> Option<String> {
-        let link = link_header?;
-        // Link header format: <url>; rel="previous"; results="false"; cursor="...", <url>; rel="next"; ...
-        for part in link.split(',') {
-            if part.contains("rel=\"next\"") && part.contains("results=\"true\"") {
-                // Extract cursor value
-                for segment in part.split(';') {
-                    let segment = segment.trim();
-                    if segment.starts_with("cursor=") {
-                        return Some(
-                            segment
-                                .trim_start_matches("cursor=")
-                                .trim_matches('"')
-                                .to_string(),
-                        );
-                    }
-                }
-            }
-        }
-        None
-    }
-
-    pub async fn list_issues(&self, params: ListIssuesParams) -> Result<Vec<Issue>> {
-        let url = self.build_issues_url(&params)?;
-
-        self.log_request("GET", &url);
-
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(&self.auth_token)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+    /// A single page of issues, with the cursors of the pages around it
+    pub async fn list_issues(&self, params: ListIssuesParams) -> Result<Page<Issue>> {
+        self.get_page(self.build_issues_url(&params)?).await
     }
 
-    /// List all issues with automatic pagination
-    pub async fn list_all_issues(&self, params: ListIssuesParams) -> Result<Vec<Issue>> {
-        let mut all_issues = Vec::new();
-        let mut cursor: Option<String> = None;
-        let mut page = 1;
-
-        loop {
-            let page_params = ListIssuesParams {
-                cursor: cursor.clone(),
-                ..params.clone()
-            };
-
-            let url = self.build_issues_url(&page_params)?;
-
-            self.log_request("GET", &url);
-            if self.verbose {
-                eprintln!("[verbose] Fetching page {}...", page);
-            }
-
-            let response = self
-                .client
-                .get(url)
-                .bearer_auth(&self.auth_token)
-                .send()
-                .await?;
-
-            let status = response.status();
-            self.log_response(status);
-
-            if !status.is_success() {
-                return Err(self.map_error_response(status, response).await);
-            }
-
-            // Get Link header before consuming response
-            let link_header = response
-                .headers()
-                .get("link")
-                .and_then(|v| v.to_str().ok())
-                .map(String::from);
-
-            let issues: Vec<Issue> = response.json().await?;
-            let count = issues.len();
-            all_issues.extend(issues);
-
-            if self.verbose {
-                eprintln!(
-                    "[verbose] Got {} issues (total: {})",
-                    count,
-                    all_issues.len()
-                );
-            }
-
-            // Check for next page
-            cursor = Self::parse_next_cursor(link_header.as_deref());
-            if cursor.is_none() {
-                break;
-            }
-            page += 1;
-        }
-
-        Ok(all_issues)
+    /// Pages of issues, fetched as the stream is polled
+    pub fn issue_pages(
+        &self,
+        params: &ListIssuesParams,
+    ) -> Result<impl Stream<Item = Result<Page<Issue>>> + '_> {
+        Ok(self.paginate(self.build_issues_url(params)?))
     }
 
     pub async fn get_issue(&self, issue_id: &str) -> Result<Issue> {
         let url = self.api_url(&format!(
             "organizations/{}/issues/{}/",
-            self.org_slug, issue_id
+            self.org()?,
+            issue_id
         ))?;
 
-        self.log_request("GET", &url);
-
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(&self.auth_token)
-            .send()
-            .await?;
+        let response = self.send(self.client.get(url)).await?;
 
         self.handle_response(response).await
     }
@@ -258,24 +283,17 @@ impl SentryClient {
     pub async fn update_issue(&self, issue_id: &str, update: IssueUpdate) -> Result<Issue> {
         let url = self.api_url(&format!(
             "organizations/{}/issues/{}/",
-            self.org_slug, issue_id
+            self.org()?,
+            issue_id
         ))?;
 
-        self.log_request("PUT", &url);
-
-        let response = self
-            .client
-            .put(url)
-            .bearer_auth(&self.auth_token)
-            .json(&update)
-            .send()
-            .await?;
+        let response = self.send(self.client.put(url).json(&update)).await?;
 
         self.handle_response(response).await
     }
 
     pub async fn update_issues(&self, issue_ids: &[String], update: IssueUpdate) -> Result<()> {
-        let mut url = self.api_url(&format!("organizations/{}/issues/", self.org_slug))?;
+        let mut url = self.api_url(&format!("organizations/{}/issues/", self.org()?))?;
 
         {
             let mut query = url.query_pairs_mut();
@@ -284,18 +302,9 @@ impl SentryClient {
             }
         }
 
-        self.log_request("PUT", &url);
-
-        let response = self
-            .client
-            .put(url)
-            .bearer_auth(&self.auth_token)
-            .json(&update)
-            .send()
-            .await?;
+        let response = self.send(self.client.put(url).json(&update)).await?;
 
         let status = response.status();
-        self.log_response(status);
 
         if status.is_success() {
             Ok(())
@@ -311,20 +320,13 @@ impl SentryClient {
     pub async fn delete_issue(&self, issue_id: &str) -> Result<()> {
         let url = self.api_url(&format!(
             "organizations/{}/issues/{}/",
-            self.org_slug, issue_id
+            self.org()?,
+            issue_id
         ))?;
 
-        self.log_request("DELETE", &url);
-
-        let response = self
-            .client
-            .delete(url)
-            .bearer_auth(&self.auth_token)
-            .send()
-            .await?;
+        let response = self.send(self.client.delete(url)).await?;
 
         let status = response.status();
-        self.log_response(status);
 
         if status.is_success() {
             Ok(())
@@ -338,7 +340,7 @@ impl SentryClient {
     }
 
     pub async fn delete_issues(&self, issue_ids: &[String]) -> Result<()> {
-        let mut url = self.api_url(&format!("organizations/{}/issues/", self.org_slug))?;
+        let mut url = self.api_url(&format!("organizations/{}/issues/", self.org()?))?;
 
         {
             let mut query = url.query_pairs_mut();
@@ -347,17 +349,9 @@ impl SentryClient {
             }
         }
 
-        self.log_request("DELETE", &url);
-
-        let response = self
-            .client
-            .delete(url)
-            .bearer_auth(&self.auth_token)
-            .send()
-            .await?;
+        let response = self.send(self.client.delete(url)).await?;
 
         let status = response.status();
-        self.log_response(status);
 
         if status.is_success() {
             Ok(())
@@ -370,11 +364,11 @@ impl SentryClient {
         }
     }
 
-    pub async fn merge_issues(&self, primary_id: &str, other_ids: &[String]) -> Result<Issue> {
+    pub async fn merge_issues(&self, primary_id: &str, other_ids: &[String]) -> Result<IssueMerge> {
         let mut all_ids = vec![primary_id.to_string()];
         all_ids.extend(other_ids.iter().cloned());
 
-        let mut url = self.api_url(&format!("organizations/{}/issues/", self.org_slug))?;
+        let mut url = self.api_url(&format!("organizations/{}/issues/", self.org()?))?;
 
         {
             let mut query = url.query_pairs_mut();
@@ -388,15 +382,365 @@ impl SentryClient {
             ..Default::default()
         };
 
-        self.log_request("PUT", &url);
+        let response = self.send(self.client.put(url).json(&update)).await?;
+
+        self.handle_response(response).await
+    }
+
+    fn issue_events_url(&self, issue_id: &str, query: Option<&str>, full: bool) -> Result<Url> {
+        let mut url = self.api_url(&format!(
+            "organizations/{}/issues/{}/events/",
+            self.org()?,
+            issue_id
+        ))?;
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            if let Some(q) = query {
+                query_pairs.append_pair("query", q);
+            }
+            if full {
+                query_pairs.append_pair("full", "true");
+            }
+        }
+
+        Ok(url)
+    }
+
+    pub async fn list_issue_events(
+        &self,
+        issue_id: &str,
+        query: Option<&str>,
+        full: bool,
+    ) -> Result<Vec<Event>> {
+        let url = self.issue_events_url(issue_id, query, full)?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Pages of an issue's events, newest first, fetched as the stream is polled
+    pub fn issue_event_pages(
+        &self,
+        issue_id: &str,
+        query: Option<&str>,
+        full: bool,
+    ) -> Result<impl Stream<Item = Result<Page<Event>>> + '_> {
+        Ok(self.paginate(self.issue_events_url(issue_id, query, full)?))
+    }
+
+    /// Fetch a single event of an issue. `event_id` may also be `latest`,
+    /// `oldest` or `recommended`.
+    pub async fn get_issue_event(&self, issue_id: &str, event_id: &str) -> Result<Event> {
+        let url = self.api_url(&format!(
+            "organizations/{}/issues/{}/events/{}/",
+            self.org()?,
+            issue_id,
+            event_id
+        ))?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn create_release(&self, release: &NewRelease) -> Result<Release> {
+        let url = self.api_url(&format!("organizations/{}/releases/", self.org()?))?;
+
+        let response = self.send(self.client.post(url).json(release)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn list_releases(&self, params: &ListReleasesParams) -> Result<Vec<Release>> {
+        let path = match &params.project {
+            Some(project) => format!("projects/{}/{}/releases/", self.org()?, project),
+            None => format!("organizations/{}/releases/", self.org()?),
+        };
+        let mut url = self.api_url(&path)?;
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            if let Some(query) = &params.query {
+                query_pairs.append_pair("query", query);
+            }
+            if let Some(limit) = params.limit {
+                query_pairs.append_pair("per_page", &limit.to_string());
+            }
+        }
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn get_release(&self, version: &str) -> Result<Release> {
+        let url = self.release_url(version, &[])?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn update_release(&self, version: &str, update: &ReleaseUpdate) -> Result<Release> {
+        let url = self.release_url(version, &[])?;
+
+        let response = self.send(self.client.put(url).json(update)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn delete_release(&self, version: &str) -> Result<()> {
+        let url = self.release_url(version, &[])?;
+
+        let response = self.send(self.client.delete(url)).await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    /// The release before `version` that has commits associated, if any
+    pub async fn get_previous_release(&self, version: &str) -> Result<PreviousRelease> {
+        let url = self.release_url(version, &["previous-with-commits"])?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn create_deploy(&self, version: &str, deploy: &NewDeploy) -> Result<Deploy> {
+        let url = self.release_url(version, &["deploys"])?;
+
+        let response = self.send(self.client.post(url).json(deploy)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn list_deploys(&self, version: &str) -> Result<Vec<Deploy>> {
+        let url = self.release_url(version, &["deploys"])?;
+
+        self.list_all(url).await
+    }
+
+    /// Describe the token in use: its user and scopes
+    pub async fn get_auth_info(&self) -> Result<AuthInfo> {
+        let url = self.api_url("")?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Organizations the authenticated user is a member of
+    pub async fn list_organizations(&self) -> Result<Vec<Organization>> {
+        let url = self.api_url("organizations/")?;
+
+        self.list_all(url).await
+    }
+
+    pub async fn get_organization(&self, org: &str) -> Result<Organization> {
+        let url = self.api_url(&format!("organizations/{}/", org))?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn list_teams(&self) -> Result<Vec<Team>> {
+        let url = self.api_url(&format!("organizations/{}/teams/", self.org()?))?;
+
+        self.list_all(url).await
+    }
+
+    pub async fn create_team(&self, team: &NewTeam) -> Result<Team> {
+        let url = self.api_url(&format!("organizations/{}/teams/", self.org()?))?;
+
+        let response = self.send(self.client.post(url).json(team)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn list_team_members(&self, team: &str) -> Result<Vec<TeamMember>> {
+        let url = self.api_url(&format!("teams/{}/{}/members/", self.org()?, team))?;
+
+        self.list_all(url).await
+    }
+
+    pub async fn list_projects(&self) -> Result<Vec<Project>> {
+        let url = self.api_url(&format!("organizations/{}/projects/", self.org()?))?;
+
+        self.list_all(url).await
+    }
+
+    pub async fn get_project(&self, project: &str) -> Result<Project> {
+        let url = self.api_url(&format!("projects/{}/{}/", self.org()?, project))?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Projects are created on behalf of a team, which gets access to it
+    pub async fn create_project(&self, team: &str, project: &NewProject) -> Result<Project> {
+        let url = self.api_url(&format!("teams/{}/{}/projects/", self.org()?, team))?;
+
+        let response = self.send(self.client.post(url).json(project)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn delete_project(&self, project: &str) -> Result<()> {
+        let url = self.api_url(&format!("projects/{}/{}/", self.org()?, project))?;
+
+        let response = self.send(self.client.delete(url)).await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    pub async fn list_project_keys(&self, project: &str) -> Result<Vec<ProjectKey>> {
+        let url = self.api_url(&format!("projects/{}/{}/keys/", self.org()?, project))?;
+
+        self.list_all(url).await
+    }
+
+    pub async fn create_project_key(
+        &self,
+        project: &str,
+        key: &NewProjectKey,
+    ) -> Result<ProjectKey> {
+        let url = self.api_url(&format!("projects/{}/{}/keys/", self.org()?, project))?;
+
+        let response = self.send(self.client.post(url).json(key)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn list_monitors(&self) -> Result<Vec<Monitor>> {
+        let url = self.api_url(&format!("organizations/{}/monitors/", self.org()?))?;
+
+        self.list_all(url).await
+    }
+
+    pub async fn get_monitor(&self, monitor: &str) -> Result<Monitor> {
+        let url = self.api_url(&format!(
+            "organizations/{}/monitors/{}/",
+            self.org()?,
+            monitor
+        ))?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn list_checkins(&self, monitor: &str, limit: u32) -> Result<Vec<CheckIn>> {
+        let mut url = self.api_url(&format!(
+            "organizations/{}/monitors/{}/checkins/",
+            self.org()?,
+            monitor
+        ))?;
+        url.query_pairs_mut()
+            .append_pair("per_page", &limit.to_string());
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Send a check-in. If the check-in carries a monitor config, the monitor
+    /// is created or updated first.
+    pub async fn create_checkin(
+        &self,
+        monitor: &str,
+        checkin: &NewCheckIn,
+    ) -> Result<CheckInCreated> {
+        let url = self.api_url(&format!(
+            "organizations/{}/monitors/{}/checkins/",
+            self.org()?,
+            monitor
+        ))?;
+
+        let response = self.send(self.client.post(url).json(checkin)).await?;
+
+        self.handle_response(response).await
+    }
+
+    pub async fn update_checkin(
+        &self,
+        monitor: &str,
+        checkin_id: &str,
+        update: &CheckInUpdate,
+    ) -> Result<()> {
+        let url = self.api_url(&format!(
+            "organizations/{}/monitors/{}/checkins/{}/",
+            self.org()?,
+            monitor,
+            checkin_id
+        ))?;
+
+        let response = self.send(self.client.put(url).json(update)).await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    pub async fn get_chunk_upload_options(&self) -> Result<ChunkUploadOptions> {
+        let url = self.api_url(&format!("organizations/{}/chunk-upload/", self.org()?))?;
+
+        let response = self.send(self.client.get(url)).await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Upload a batch of chunks in one multipart request. Each chunk is given
+    /// as `(checksum, data)`; the server identifies chunks by their checksum.
+    pub async fn upload_chunks(
+        &self,
+        options: &ChunkUploadOptions,
+        chunks: &[(&str, &[u8])],
+    ) -> Result<()> {
+        // The upload URL may be absolute or relative to the server
+        let url = self.base_url.join(&options.url)?;
+
+        let form = chunks.iter().fold(Form::new(), |form, (checksum, data)| {
+            form.part(
+                "file",
+                Part::bytes(data.to_vec()).file_name(checksum.to_string()),
+            )
+        });
+
+        let response = self.send(self.client.post(url).multipart(form)).await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    pub async fn assemble_artifact_bundle(
+        &self,
+        request: &AssembleBundleRequest,
+    ) -> Result<AssembleResponse> {
+        let url = self.api_url(&format!(
+            "organizations/{}/artifactbundle/assemble/",
+            self.org()?
+        ))?;
+
+        let response = self.send(self.client.post(url).json(request)).await?;
+
+        self.handle_response(response).await
+    }
+
+    /// Ask the server to assemble debug information files from uploaded
+    /// chunks. Requests and responses are keyed by each file's checksum.
+    pub async fn assemble_difs(
+        &self,
+        project: &str,
+        files: &BTreeMap<String, AssembleDifRequest>,
+    ) -> Result<BTreeMap<String, AssembleDifResponse>> {
+        let url = self.api_url(&format!(
+            "projects/{}/{}/files/difs/assemble/",
+            self.org()?,
+            project
+        ))?;
 
-        let response = self
-            .client
-            .put(url)
-            .bearer_auth(&self.auth_token)
-            .json(&update)
-            .send()
-            .await?;
+        let response = self.send(self.client.post(url).json(files)).await?;
 
         self.handle_response(response).await
     }
@@ -0,0 +1,90 @@
+use crate::error::{Result, SentryCliError};
+use std::fmt;
+use std::str::FromStr;
+use url::Url;
+
+/// A parsed client key (DSN): `{scheme}://{public_key}@{host}/{path}{project_id}`
+#[derive(Debug, Clone)]
+pub struct Dsn {
+    url: Url,
+    public_key: String,
+    project_id: String,
+}
+
+impl Dsn {
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+
+    pub fn host(&self) -> &str {
+        self.url.host_str().unwrap_or_default()
+    }
+
+    /// The envelope ingestion endpoint for the DSN's project
+    pub fn envelope_url(&self) -> Result<Url> {
+        let mut url = self.url.clone();
+        url.set_username("")
+            .and_then(|_| url.set_password(None))
+            .map_err(|_| SentryCliError::Validation("Invalid DSN".to_string()))?;
+
+        // Sentry may be hosted under a path prefix, which precedes /api/
+        let prefix = self
+            .url
+            .path()
+            .trim_end_matches('/')
+            .trim_end_matches(&self.project_id)
+            .trim_end_matches('/')
+            .to_string();
+        url.set_path(&format!("{}/api/{}/envelope/", prefix, self.project_id));
+
+        Ok(url)
+    }
+
+    /// Value of the `X-Sentry-Auth` header for ingestion requests
+    pub fn auth_header(&self) -> String {
+        format!(
+            "Sentry sentry_version=7, sentry_key={}, sentry_client=sentry-cli/{}",
+            self.public_key,
+            env!("CARGO_PKG_VERSION")
+        )
+    }
+}
+
+impl FromStr for Dsn {
+    type Err = SentryCliError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = |reason: &str| SentryCliError::Validation(format!("Invalid DSN: {}", reason));
+
+        let url = Url::parse(s.trim()).map_err(|e| invalid(&e.to_string()))?;
+
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(invalid("scheme must be http or https"));
+        }
+        if url.username().is_empty() {
+            return Err(invalid("missing public key"));
+        }
+        if url.host_str().is_none() {
+            return Err(invalid("missing host"));
+        }
+
+        let project_id = url
+            .path_segments()
+            .and_then(|mut segments| segments.rfind(|s| !s.is_empty()))
+            .filter(|id| id.chars().all(|c| c.is_ascii_digit()))
+            .ok_or_else(|| invalid("missing or non-numeric project ID"))?
+            .to_string();
+
+        Ok(Dsn {
+            public_key: url.username().to_string(),
+            project_id,
+            url,
+        })
+    }
+}
+
+impl fmt::Display for Dsn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::error::{Result, SentryCliError};
+use serde_json::{json, Map, Value};
+
+/// A Sentry envelope: a JSON header line followed by items, each consisting
+/// of a JSON item header line and a payload.
+#[derive(Debug, Default)]
+pub struct Envelope {
+    headers: Map<String, Value>,
+    items: Vec<EnvelopeItem>,
+}
+
+#[derive(Debug)]
+pub struct EnvelopeItem {
+    headers: Map<String, Value>,
+    payload: Vec<u8>,
+}
+
+impl EnvelopeItem {
+    pub fn item_type(&self) -> &str {
+        self.headers
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+    }
+}
+
+impl Envelope {
+    /// An envelope carrying a single event
+    pub fn from_event(event_id: &str, event: &Value) -> Result<Self> {
+        let mut envelope = Envelope::default();
+        envelope
+            .headers
+            .insert("event_id".to_string(), json!(event_id));
+        envelope.add_item("event", serde_json::to_vec(event)?);
+        Ok(envelope)
+    }
+
+    pub fn add_item(&mut self, item_type: &str, payload: Vec<u8>) {
+        let mut headers = Map::new();
+        headers.insert("type".to_string(), json!(item_type));
+        self.items.push(EnvelopeItem { headers, payload });
+    }
+
+    pub fn event_id(&self) -> Option<&str> {
+        self.headers.get("event_id").and_then(Value::as_str)
+    }
+
+    pub fn items(&self) -> &[EnvelopeItem] {
+        &self.items
+    }
+
+    /// Parse a serialized envelope. Items either declare their payload
+    /// `length` or are terminated by a newline.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let invalid =
+            |reason: &str| SentryCliError::Validation(format!("Invalid envelope: {}", reason));
+
+        let (header_line, mut rest) = split_line(data);
+        let headers = parse_headers(header_line).ok_or_else(|| invalid("malformed header"))?;
+        let mut items = Vec::new();
+
+        while !rest.is_empty() {
+            let (line, after) = split_line(rest);
+            if line.iter().all(u8::is_ascii_whitespace) {
+                rest = after;
+                continue;
+            }
+
+            let item_headers =
+                parse_headers(line).ok_or_else(|| invalid("malformed item header"))?;
+            if !item_headers.get("type").is_some_and(Value::is_string) {
+                return Err(invalid("item header without type"));
+            }
+
+            let (payload, after) = match item_headers.get("length").and_then(Value::as_u64) {
+                Some(length) => {
+                    let length = length as usize;
+                    if after.len() < length {
+                        return Err(invalid("item payload shorter than its length"));
+                    }
+                    let (payload, after) = after.split_at(length);
+                    // The payload may be followed by a newline
+                    (payload, after.strip_prefix(b"\n").unwrap_or(after))
+                }
+                None => split_line(after),
+            };
+
+            items.push(EnvelopeItem {
+                headers: item_headers,
+                payload: payload.to_vec(),
+            });
+            rest = after;
+        }
+
+        Ok(Envelope { headers, items })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut out = serde_json::to_vec(&self.headers)?;
+        out.push(b'\n');
+
+        for item in &self.items {
+            let mut headers = item.headers.clone();
+            headers.insert("length".to_string(), json!(item.payload.len()));
+            out.extend(serde_json::to_vec(&headers)?);
+            out.push(b'\n');
+            out.extend(&item.payload);
+            out.push(b'\n');
+        }
+
+        Ok(out)
+    }
+}
+
+fn split_line(data: &[u8]) -> (&[u8], &[u8]) {
+    match data.iter().position(|&b| b == b'\n') {
+        Some(i) => (&data[..i], &data[i + 1..]),
+        None => (data, &[]),
+    }
+}
+
+fn parse_headers(line: &[u8]) -> Option<Map<String, Value>> {
+    match serde_json::from_slice(line).ok()? {
+        Value::Object(map) => Some(map),
+        _ => None,
+    }
+}
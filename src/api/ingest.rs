@@ -0,0 +1,83 @@
+use crate::api::dsn::Dsn;
+use crate::api::envelope::Envelope;
+use crate::api::models::ApiError;
+use crate::api::retry::parse_retry_after;
+use crate::error::{Result, SentryCliError};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+/// Client for the ingestion endpoints, authenticated by DSN rather than an
+/// auth token
+pub struct IngestClient {
+    client: Client,
+    dsn: Dsn,
+    verbose: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct IngestResponse {
+    #[serde(default)]
+    id: Option<String>,
+}
+
+impl IngestClient {
+    pub fn new(dsn: Dsn, verbose: bool) -> Self {
+        if verbose {
+            eprintln!("[verbose] Ingest host: {}", dsn.host());
+            eprintln!("[verbose] Project ID: {}", dsn.project_id());
+        }
+
+        Self {
+            client: Client::new(),
+            dsn,
+            verbose,
+        }
+    }
+
+    /// Send an envelope. Returns the event ID assigned by the server, if any.
+    pub async fn send_envelope(&self, envelope: &Envelope) -> Result<Option<String>> {
+        let url = self.dsn.envelope_url()?;
+        if self.verbose {
+            eprintln!("[verbose] POST {}", url);
+        }
+
+        let response = self
+            .client
+            .post(url)
+            .header("X-Sentry-Auth", self.dsn.auth_header())
+            .header("Content-Type", "application/x-sentry-envelope")
+            .body(envelope.to_bytes()?)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if self.verbose {
+            eprintln!("[verbose] Response: {}", status);
+        }
+
+        if status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let id = serde_json::from_str::<IngestResponse>(&body)
+                .ok()
+                .and_then(|r| r.id);
+            return Ok(id.or_else(|| envelope.event_id().map(String::from)));
+        }
+
+        let retry_after = parse_retry_after(response.headers())
+            .map(|d| d.as_secs())
+            .unwrap_or(60);
+        let body = response.text().await.unwrap_or_default();
+        let message = serde_json::from_str::<ApiError>(&body)
+            .map(|e| e.detail)
+            .unwrap_or(body);
+
+        Err(match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => SentryCliError::Auth(message),
+            StatusCode::TOO_MANY_REQUESTS => SentryCliError::RateLimited { retry_after },
+            _ => SentryCliError::Api {
+                status: status.as_u16(),
+                message,
+            },
+        })
+    }
+}
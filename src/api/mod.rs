@@ -1,4 +1,13 @@
 pub mod client;
+pub mod dsn;
+pub mod envelope;
+pub mod ingest;
 pub mod models;
+pub mod oauth;
+pub mod pagination;
+pub mod retry;
 
 pub use client::SentryClient;
+pub use ingest::IngestClient;
+pub use oauth::OAuthClient;
+pub use pagination::{for_each_page, Cursors};
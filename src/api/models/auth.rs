@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// Response of the API root, describing the token used to call it
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuthInfo {
+    #[serde(default)]
+    pub user: Option<AuthUser>,
+    #[serde(default)]
+    pub auth: Option<TokenScopes>,
+}
+
+impl AuthInfo {
+    pub fn scopes(&self) -> &[String] {
+        self.auth.as_ref().map_or(&[], |auth| &auth.scopes)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuthUser {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    pub username: String,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TokenScopes {
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// OAuth device authorization response (RFC 8628)
+#[derive(Debug, Deserialize)]
+pub struct DeviceAuthorization {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    #[serde(default)]
+    pub verification_uri_complete: Option<String>,
+    pub expires_in: u64,
+    #[serde(default)]
+    pub interval: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OAuthToken {
+    pub access_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OAuthError {
+    pub error: String,
+    #[serde(default)]
+    pub error_description: Option<String>,
+}
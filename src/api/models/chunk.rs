@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// Server capabilities for chunked uploads, from the `chunk-upload` endpoint
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkUploadOptions {
+    pub url: String,
+    pub chunk_size: usize,
+    pub chunks_per_request: usize,
+    pub max_request_size: usize,
+    pub hash_algorithm: String,
+    /// Upload types the server accepts (e.g. `artifact_bundles`, `debug_files`)
+    #[serde(default)]
+    pub accept: Vec<String>,
+}
+
+impl ChunkUploadOptions {
+    pub fn accepts(&self, kind: &str) -> bool {
+        self.accept.iter().any(|a| a == kind)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChunkedFileState {
+    Error,
+    NotFound,
+    Created,
+    Assembling,
+    Ok,
+}
+
+impl ChunkedFileState {
+    pub fn is_pending(self) -> bool {
+        matches!(
+            self,
+            ChunkedFileState::Created | ChunkedFileState::Assembling
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssembleBundleRequest {
+    pub checksum: String,
+    pub chunks: Vec<String>,
+    pub projects: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dist: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssembleResponse {
+    pub state: ChunkedFileState,
+    #[serde(default)]
+    pub missing_chunks: Vec<String>,
+    #[serde(default)]
+    pub detail: Option<String>,
+}
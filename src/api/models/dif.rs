@@ -0,0 +1,21 @@
+use super::ChunkedFileState;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssembleDifRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug_id: Option<String>,
+    pub chunks: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssembleDifResponse {
+    pub state: ChunkedFileState,
+    #[serde(default)]
+    pub missing_chunks: Vec<String>,
+    #[serde(default)]
+    pub detail: Option<String>,
+}
@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    pub id: String,
+    #[serde(rename = "eventID")]
+    pub event_id: String,
+    #[serde(rename = "groupID", default)]
+    pub group_id: Option<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub platform: Option<String>,
+    #[serde(default)]
+    pub culprit: Option<String>,
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub date_received: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub user: Option<EventUser>,
+    #[serde(default)]
+    pub tags: Vec<EventTag>,
+    #[serde(default)]
+    pub contexts: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub entries: Vec<EventEntry>,
+    #[serde(default)]
+    pub sdk: Option<EventSdk>,
+}
+
+impl Event {
+    /// Exception values from all exception entries, in the order Sentry sent them
+    pub fn exceptions(&self) -> Vec<ExceptionValue> {
+        self.entries_of::<ExceptionData>("exception")
+            .flat_map(|data| data.values)
+            .collect()
+    }
+
+    pub fn breadcrumbs(&self) -> Vec<Breadcrumb> {
+        self.entries_of::<BreadcrumbsData>("breadcrumbs")
+            .flat_map(|data| data.values)
+            .collect()
+    }
+
+    /// The formatted message from a message entry, falling back to the event message
+    pub fn formatted_message(&self) -> Option<String> {
+        self.entries_of::<MessageData>("message")
+            .find_map(|data| data.formatted)
+            .or_else(|| self.message.clone().filter(|m| !m.is_empty()))
+    }
+
+    fn entries_of<'a, T: serde::de::DeserializeOwned + 'a>(
+        &'a self,
+        entry_type: &'a str,
+    ) -> impl Iterator<Item = T> + 'a {
+        self.entries
+            .iter()
+            .filter(move |e| e.entry_type == entry_type)
+            .filter_map(|e| serde_json::from_value(e.data.clone()).ok())
+    }
+}
+
+/// A raw event entry. The shape of `data` depends on `type`; use the typed
+/// accessors on `Event` to read the entries the CLI understands.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EventEntry {
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    #[serde(default)]
+    pub data: Value,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EventTag {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EventUser {
+    pub id: Option<String>,
+    pub email: Option<String>,
+    pub username: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EventSdk {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExceptionData {
+    #[serde(default)]
+    values: Vec<ExceptionValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BreadcrumbsData {
+    #[serde(default)]
+    values: Vec<Breadcrumb>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessageData {
+    formatted: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExceptionValue {
+    #[serde(rename = "type")]
+    pub exception_type: Option<String>,
+    pub value: Option<String>,
+    pub module: Option<String>,
+    pub stacktrace: Option<Stacktrace>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Stacktrace {
+    /// Frames ordered from outermost call to innermost (crashing) call
+    #[serde(default)]
+    pub frames: Vec<Frame>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Frame {
+    pub filename: Option<String>,
+    pub abs_path: Option<String>,
+    pub module: Option<String>,
+    pub function: Option<String>,
+    pub line_no: Option<u32>,
+    pub col_no: Option<u32>,
+    #[serde(default)]
+    pub in_app: bool,
+    /// Source context as `[line_number, source_line]` pairs
+    #[serde(default)]
+    pub context: Vec<(u32, String)>,
+}
+
+impl Frame {
+    /// The source line the frame points at, if context was captured
+    pub fn context_line(&self) -> Option<&str> {
+        let line_no = self.line_no?;
+        self.context
+            .iter()
+            .find(|(n, _)| *n == line_no)
+            .map(|(_, line)| line.as_str())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Breadcrumb {
+    pub timestamp: Option<DateTime<Utc>>,
+    #[serde(rename = "type")]
+    pub breadcrumb_type: Option<String>,
+    pub category: Option<String>,
+    pub message: Option<String>,
+    pub level: Option<String>,
+    #[serde(default)]
+    pub data: Option<Value>,
+}
@@ -97,6 +97,20 @@ pub struct StatusDetails {
     pub ignore_until_escalating: Option<bool>,
 }
 
+/// Response to merging issues: `{"merge": {"parent": ..., "children": [...]}}`
+#[derive(Debug, Deserialize)]
+pub struct IssueMerge {
+    pub merge: MergeDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergeDetails {
+    /// ID of the issue the others were merged into
+    pub parent: String,
+    #[serde(default)]
+    pub children: Vec<String>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct ListIssuesParams {
     pub project: Option<Vec<String>>,
@@ -1,5 +1,25 @@
+mod auth;
+mod chunk;
 mod common;
+mod deploy;
+mod dif;
+mod event;
 mod issue;
+mod monitor;
+mod organization;
+mod project;
+mod release;
+mod team;
 
+pub use auth::*;
+pub use chunk::*;
 pub use common::*;
+pub use deploy::*;
+pub use dif::*;
+pub use event::*;
 pub use issue::*;
+pub use monitor::*;
+pub use organization::*;
+pub use project::*;
+pub use release::*;
+pub use team::*;
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Monitor {
+    pub id: String,
+    pub slug: String,
+    pub name: String,
+    pub status: String,
+    #[serde(default)]
+    pub is_muted: bool,
+    #[serde(default)]
+    pub project: Option<MonitorProject>,
+    pub config: MonitorConfig,
+    #[serde(default)]
+    pub environments: Vec<MonitorEnvironment>,
+    pub date_created: DateTime<Utc>,
+}
+
+impl Monitor {
+    /// Most recent check-in across all environments
+    pub fn last_check_in(&self) -> Option<DateTime<Utc>> {
+        self.environments
+            .iter()
+            .filter_map(|e| e.last_check_in)
+            .max()
+    }
+
+    /// Earliest expected check-in across all environments
+    pub fn next_check_in(&self) -> Option<DateTime<Utc>> {
+        self.environments
+            .iter()
+            .filter_map(|e| e.next_check_in)
+            .min()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MonitorProject {
+    pub slug: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorEnvironment {
+    pub name: String,
+    pub status: String,
+    #[serde(default)]
+    pub last_check_in: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub next_check_in: Option<DateTime<Utc>>,
+}
+
+/// Monitor configuration as returned by the API. The API uses snake_case here.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MonitorConfig {
+    #[serde(default)]
+    pub schedule_type: Option<String>,
+    /// A crontab string, or `[value, unit]` for interval schedules
+    #[serde(default)]
+    pub schedule: Value,
+    #[serde(default)]
+    pub checkin_margin: Option<u32>,
+    #[serde(default)]
+    pub max_runtime: Option<u32>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+impl MonitorConfig {
+    pub fn schedule_display(&self) -> String {
+        match &self.schedule {
+            Value::String(crontab) => crontab.clone(),
+            Value::Array(parts) => match parts.as_slice() {
+                [value, Value::String(unit)] => format!("every {} {}(s)", value, unit),
+                _ => self.schedule.to_string(),
+            },
+            Value::Null => String::new(),
+            other => other.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MonitorSchedule {
+    Crontab { value: String },
+    Interval { value: u32, unit: String },
+}
+
+/// Monitor configuration sent along with a check-in, creating or updating
+/// the monitor on the server
+#[derive(Debug, Serialize)]
+pub struct MonitorConfigUpsert {
+    pub schedule: MonitorSchedule,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkin_margin: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_runtime: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckInStatus {
+    InProgress,
+    Ok,
+    Error,
+    Missed,
+    Timeout,
+    #[serde(other)]
+    Unknown,
+}
+
+impl std::fmt::Display for CheckInStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckInStatus::InProgress => write!(f, "in_progress"),
+            CheckInStatus::Ok => write!(f, "ok"),
+            CheckInStatus::Error => write!(f, "error"),
+            CheckInStatus::Missed => write!(f, "missed"),
+            CheckInStatus::Timeout => write!(f, "timeout"),
+            CheckInStatus::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckIn {
+    pub id: String,
+    pub status: CheckInStatus,
+    /// Duration in milliseconds
+    #[serde(default)]
+    pub duration: Option<u64>,
+    #[serde(default)]
+    pub environment: Option<String>,
+    pub date_created: DateTime<Utc>,
+}
+
+/// Response to creating a check-in
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CheckInCreated {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewCheckIn {
+    pub status: CheckInStatus,
+    /// Duration in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitor_config: Option<MonitorConfigUpsert>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckInUpdate {
+    pub status: CheckInStatus,
+    /// Duration in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u64>,
+}
@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Release {
+    pub version: String,
+    #[serde(default)]
+    pub short_version: Option<String>,
+    #[serde(rename = "ref", default)]
+    pub git_ref: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    pub date_created: DateTime<Utc>,
+    #[serde(default)]
+    pub date_released: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub new_groups: u64,
+    #[serde(default)]
+    pub commit_count: u64,
+    #[serde(default)]
+    pub last_commit: Option<ReleaseCommit>,
+    #[serde(default)]
+    pub deploy_count: u64,
+    #[serde(default)]
+    pub projects: Vec<ReleaseProject>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReleaseProject {
+    pub name: String,
+    pub slug: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseCommit {
+    pub id: String,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub date_created: Option<DateTime<Utc>>,
+}
+
+/// Response of the `previous-with-commits` endpoint
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviousRelease {
+    #[serde(default)]
+    pub last_commit: Option<ReleaseCommit>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewRelease {
+    pub version: String,
+    pub projects: Vec<String>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_released: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseUpdate {
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_released: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commits: Option<Vec<CommitSpec>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refs: Option<Vec<RefSpec>>,
+}
+
+/// A commit sent with its metadata, for repositories Sentry cannot read itself
+#[derive(Debug, Serialize)]
+pub struct CommitSpec {
+    pub id: String,
+    pub repository: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+/// A commit range resolved by Sentry through a repository integration
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefSpec {
+    pub repository: String,
+    pub commit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_commit: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ListReleasesParams {
+    pub project: Option<String>,
+    pub query: Option<String>,
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    pub id: String,
+    pub slug: String,
+    pub name: String,
+    #[serde(default)]
+    pub member_count: u32,
+    #[serde(default)]
+    pub is_member: bool,
+    pub date_created: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewTeam {
+    pub slug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMember {
+    pub id: String,
+    pub email: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Organization role, e.g. `member` or `owner`
+    #[serde(default)]
+    pub role: Option<String>,
+    /// Role within the team, e.g. `contributor` or `admin`
+    #[serde(default)]
+    pub team_role: Option<String>,
+    #[serde(default)]
+    pub pending: bool,
+}
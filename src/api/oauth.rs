@@ -0,0 +1,107 @@
+use crate::api::models::{DeviceAuthorization, OAuthError, OAuthToken};
+use crate::error::{Result, SentryCliError};
+use reqwest::Client;
+use std::time::{Duration, Instant};
+use url::Url;
+
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+const DEFAULT_POLL_INTERVAL: u64 = 5;
+
+/// Client for the OAuth device authorization flow, which obtains a token
+/// without one being pasted into the terminal
+pub struct OAuthClient {
+    client: Client,
+    base_url: Url,
+    client_id: String,
+    verbose: bool,
+}
+
+impl OAuthClient {
+    pub fn new(server_url: &str, client_id: &str, verbose: bool) -> Result<Self> {
+        Ok(Self {
+            client: Client::new(),
+            base_url: Url::parse(server_url)?,
+            client_id: client_id.to_string(),
+            verbose,
+        })
+    }
+
+    /// Start the flow: the user enters the returned code at the
+    /// verification URL
+    pub async fn request_device_code(&self, scopes: &[&str]) -> Result<DeviceAuthorization> {
+        let scope = scopes.join(" ");
+        let form = [("client_id", self.client_id.as_str()), ("scope", &scope)];
+        self.post("/oauth/device/code/", &form)
+            .await?
+            .map_err(oauth_error)
+    }
+
+    /// Poll until the user approves or denies the request, or it expires
+    pub async fn wait_for_token(&self, authorization: &DeviceAuthorization) -> Result<String> {
+        let deadline = Instant::now() + Duration::from_secs(authorization.expires_in);
+        let mut interval = authorization.interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+        let form = [
+            ("client_id", self.client_id.as_str()),
+            ("device_code", &authorization.device_code),
+            ("grant_type", DEVICE_CODE_GRANT),
+        ];
+
+        while Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+
+            match self.post::<OAuthToken>("/oauth/token/", &form).await? {
+                Ok(token) => return Ok(token.access_token),
+                Err(e) if e.error == "authorization_pending" => {}
+                // The server asks for a longer interval on every slow_down
+                Err(e) if e.error == "slow_down" => interval += 5,
+                Err(e) => return Err(oauth_error(e)),
+            }
+        }
+
+        Err(SentryCliError::Auth(
+            "The login request expired. Run 'sentry login --device' again".to_string(),
+        ))
+    }
+
+    /// POST a form. OAuth errors are returned as the inner `Err`, so the
+    /// caller can tell pending authorizations from failures.
+    async fn post<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<std::result::Result<T, OAuthError>> {
+        let url = self.base_url.join(path)?;
+        if self.verbose {
+            eprintln!("[verbose] POST {}", url);
+        }
+
+        let response = self.client.post(url).form(form).send().await?;
+        let status = response.status();
+        if self.verbose {
+            eprintln!("[verbose] Response: {}", status);
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        if status.is_success() {
+            return Ok(Ok(serde_json::from_str(&body)?));
+        }
+
+        serde_json::from_str::<OAuthError>(&body)
+            .map(Err)
+            .map_err(|_| SentryCliError::Api {
+                status: status.as_u16(),
+                message: body,
+            })
+    }
+}
+
+fn oauth_error(e: OAuthError) -> SentryCliError {
+    let message = e.error_description.unwrap_or_else(|| e.error.clone());
+    match e.error.as_str() {
+        "access_denied" => SentryCliError::Auth("The login request was denied".to_string()),
+        "expired_token" => SentryCliError::Auth(
+            "The login request expired. Run 'sentry login --device' again".to_string(),
+        ),
+        _ => SentryCliError::Auth(message),
+    }
+}
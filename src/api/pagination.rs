@@ -0,0 +1,168 @@
+use super::SentryClient;
+use crate::error::Result;
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+/// One page of results from a list endpoint
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor the page was requested with, if it is not the first page
+    pub cursor: Option<String>,
+    /// Cursor of the following page, if the server has more results
+    pub next_cursor: Option<String>,
+    /// Cursor of the preceding page, if there is one
+    pub prev_cursor: Option<String>,
+}
+
+/// Where a paged listing can continue from
+#[derive(Debug, Default)]
+pub struct Cursors {
+    pub next: Option<String>,
+    pub prev: Option<String>,
+}
+
+impl SentryClient {
+    /// Fetch a single page of a list endpoint
+    pub(super) async fn get_page<T: DeserializeOwned>(&self, url: Url) -> Result<Page<T>> {
+        let cursor = url
+            .query_pairs()
+            .find(|(key, _)| key == "cursor")
+            .map(|(_, value)| value.into_owned());
+        let response = self.send(self.client.get(url)).await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(self.map_error_response(status, response).await);
+        }
+
+        // Read the Link header before the body consumes the response
+        let link = response.headers().get("link").and_then(|v| v.to_str().ok());
+        let next_cursor = parse_cursor(link, "next");
+        let prev_cursor = parse_cursor(link, "previous");
+        let items = response.json().await?;
+
+        Ok(Page {
+            items,
+            cursor,
+            next_cursor,
+            prev_cursor,
+        })
+    }
+
+    /// Pages of a list endpoint starting at `url`, fetched lazily by following
+    /// the cursors in the `Link` header. Nothing is requested until the stream
+    /// is polled, so callers can stop early without fetching further pages.
+    ///
+    /// Each page is fetched through the retry layer, so a rate limit or
+    /// gateway error mid-way resumes from the current cursor instead of
+    /// discarding the pages fetched so far.
+    pub fn paginate<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        url: Url,
+    ) -> impl Stream<Item = Result<Page<T>>> + 'a {
+        stream::try_unfold(Some(url), move |next| async move {
+            let Some(url) = next else {
+                return Ok(None);
+            };
+            let page: Page<T> = self.get_page(url.clone()).await?;
+            let next = page
+                .next_cursor
+                .as_deref()
+                .map(|cursor| with_cursor(&url, cursor));
+            Ok(Some((page, next)))
+        })
+    }
+
+    /// Every item of a list endpoint, across all pages
+    pub(super) async fn list_all<T: DeserializeOwned>(&self, url: Url) -> Result<Vec<T>> {
+        self.paginate(url)
+            .map_ok(|page: Page<T>| page.items)
+            .try_concat()
+            .await
+    }
+}
+
+/// `url` with its `cursor` query parameter set to `cursor`
+fn with_cursor(url: &Url, cursor: &str) -> Url {
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| key != "cursor")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    let mut url = url.clone();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair("cursor", cursor);
+    url
+}
+
+/// Parse the Link header to find the cursor of the `rel` ("next" or
+/// "previous") page, if that page has results
+fn parse_cursor(link_header: Option<&str>, rel: &str) -> Option<String> {
+    let link = link_header?;
+    let rel = format!("rel=\"{}\"", rel);
+    // Link header format: <url>; rel="previous"; results="false"; cursor="...", <url>; rel="next"; ...
+    for part in link.split(',') {
+        if part.contains(&rel) && part.contains("results=\"true\"") {
+            // Extract cursor value
+            for segment in part.split(';') {
+                let segment = segment.trim();
+                if segment.starts_with("cursor=") {
+                    return Some(
+                        segment
+                            .trim_start_matches("cursor=")
+                            .trim_matches('"')
+                            .to_string(),
+                    );
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Hand the items of `pages` to `f` one page at a time, stopping once `max`
+/// items have been seen. Pages after that are never requested. An error
+/// from `f` stops the iteration.
+///
+/// `cursors` is kept up to date as pages arrive, so that it tells where to
+/// resume even if a page fails. If the last page had to be cut short, the
+/// next cursor points at that page again, so resuming repeats some items
+/// rather than skipping any.
+pub async fn for_each_page<T, S>(
+    pages: S,
+    max: Option<usize>,
+    cursors: &mut Cursors,
+    mut f: impl FnMut(Vec<T>) -> Result<()>,
+) -> Result<()>
+where
+    S: Stream<Item = Result<Page<T>>>,
+{
+    let mut pages = std::pin::pin!(pages);
+    let mut remaining = max.unwrap_or(usize::MAX);
+    let mut first = true;
+
+    while remaining > 0 {
+        let Some(page) = pages.try_next().await? else {
+            break;
+        };
+        if std::mem::take(&mut first) {
+            cursors.prev = page.prev_cursor;
+        }
+
+        let mut items = page.items;
+        if items.len() > remaining {
+            items.truncate(remaining);
+            cursors.next = page.cursor;
+        } else {
+            cursors.next = page.next_cursor;
+        }
+        remaining -= items.len();
+        f(items)?;
+    }
+
+    Ok(())
+}
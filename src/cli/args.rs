@@ -1,12 +1,22 @@
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
+use std::path::PathBuf;
+use std::time::Duration;
 
-#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
 pub enum OutputFormat {
     #[default]
     Table,
     Json,
+    /// One line per item, without borders or headers
     Compact,
+    Csv,
+    Tsv,
+    /// One JSON object per line
+    Ndjson,
+    Yaml,
+    /// Markdown table
+    Markdown,
 }
 
 #[derive(Parser)]
@@ -31,10 +41,37 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub token: Option<String>,
 
-    /// Output format (table, json, compact)
-    #[arg(long, short = 'O', global = true, value_enum, default_value = "table")]
+    /// Configuration profile to use (overrides SENTRY_PROFILE and the active profile)
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+
+    /// Output format
+    #[arg(
+        long = "output",
+        short = 'O',
+        visible_alias = "format",
+        global = true,
+        value_enum,
+        default_value = "table"
+    )]
     pub format: OutputFormat,
 
+    /// Filter the JSON output with a jq expression, e.g. '.issues[].shortId'
+    #[arg(long, global = true, value_name = "EXPR", conflicts_with_all = ["template", "template_file"])]
+    pub jq: Option<String>,
+
+    /// Print each item with a Handlebars template instead, e.g. '{{short_id}} {{title}}'
+    #[arg(long, global = true, conflicts_with = "template_file")]
+    pub template: Option<String>,
+
+    /// Read the output template from a file
+    #[arg(long, global = true, value_name = "PATH")]
+    pub template_file: Option<PathBuf>,
+
+    /// Maximum retries for rate-limited or transient API failures (default: 3)
+    #[arg(long, global = true)]
+    pub max_retries: Option<u32>,
+
     /// Suppress success messages
     #[arg(long, short, global = true)]
     pub quiet: bool,
@@ -50,20 +87,171 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// Manage Sentry issues
-    #[command(alias = "i", after_help = "EXAMPLES:
+    #[command(
+        alias = "i",
+        after_help = "EXAMPLES:
     sentry issues list --project myproject
     sentry issues list --status unresolved --limit 50
     sentry issues view ISSUE-123
-    sentry issues resolve ISSUE-123 ISSUE-456")]
+    sentry issues resolve ISSUE-123 ISSUE-456"
+    )]
     Issues {
         #[command(subcommand)]
         command: IssuesCommands,
     },
+    /// Inspect issue events and stack traces
+    #[command(
+        alias = "ev",
+        after_help = "EXAMPLES:
+    sentry events list ISSUE-123
+    sentry events view ISSUE-123
+    sentry events view ISSUE-123 oldest
+    sentry events view ISSUE-123 9fac2ceed9344f2bbfdd1fdacb0ed9b1 --in-app"
+    )]
+    Events {
+        #[command(subcommand)]
+        command: EventsCommands,
+    },
+    /// Manage releases
+    #[command(
+        alias = "rel",
+        after_help = "EXAMPLES:
+    sentry releases new 1.2.3 --project backend
+    sentry releases set-commits 1.2.3 --auto
+    sentry releases finalize 1.2.3
+    sentry releases list --project backend"
+    )]
+    Releases {
+        #[command(subcommand)]
+        command: ReleasesCommands,
+    },
+    /// Inject debug IDs into and upload JavaScript source maps
+    #[command(after_help = "EXAMPLES:
+    sentry sourcemaps inject ./dist
+    sentry sourcemaps upload ./dist --project frontend
+    sentry sourcemaps upload ./dist --project frontend --release 1.2.3")]
+    Sourcemaps {
+        #[command(subcommand)]
+        command: SourcemapsCommands,
+    },
+    /// Manage projects and their client keys (DSNs)
+    #[command(
+        alias = "proj",
+        after_help = "EXAMPLES:
+    sentry projects list
+    sentry projects create backend --team platform --platform python
+    sentry projects keys list backend"
+    )]
+    Projects {
+        #[command(subcommand)]
+        command: ProjectsCommands,
+    },
+    /// List and inspect organizations
+    #[command(
+        alias = "org",
+        after_help = "EXAMPLES:
+    sentry orgs list
+    sentry orgs view my-org"
+    )]
+    Orgs {
+        #[command(subcommand)]
+        command: OrgsCommands,
+    },
+    /// Manage teams
+    #[command(after_help = "EXAMPLES:
+    sentry teams list
+    sentry teams create platform
+    sentry teams members platform")]
+    Teams {
+        #[command(subcommand)]
+        command: TeamsCommands,
+    },
+    /// Find, check and upload debug information files (ELF, split DWARF, Breakpad)
+    #[command(
+        name = "debug-files",
+        alias = "dif",
+        after_help = "EXAMPLES:
+    sentry debug-files check ./target/release/myapp
+    sentry debug-files upload ./target/release --project native
+    sentry debug-files find 3249d99d-0c40-4931-8610-f4e4fb0b6936 --path ./build"
+    )]
+    DebugFiles {
+        #[command(subcommand)]
+        command: DebugFilesCommands,
+    },
+    /// Manage cron monitors and send check-ins
+    #[command(
+        alias = "mon",
+        after_help = "EXAMPLES:
+    sentry monitors list
+    sentry monitors run nightly-backup -- ./backup.sh
+    sentry monitors checkin nightly-backup --status ok"
+    )]
+    Monitors {
+        #[command(subcommand)]
+        command: MonitorsCommands,
+    },
+    /// Send an event to Sentry using a DSN
+    #[command(after_help = "EXAMPLES:
+    sentry send-event -m \"Backup finished\" --level info
+    sentry send-event -m \"Deploy failed\" --tag stage:migrate --extra host:web-1
+    SENTRY_DSN=https://key@o0.ingest.sentry.io/42 sentry send-event -m \"Smoke test\"")]
+    SendEvent {
+        #[command(flatten)]
+        args: SendEventArgs,
+    },
+    /// Send a raw envelope file to Sentry using a DSN
+    #[command(after_help = "EXAMPLES:
+    sentry send-envelope ./event.envelope
+    sentry send-envelope ./event.envelope --dsn https://key@o0.ingest.sentry.io/42")]
+    SendEnvelope {
+        /// Path to the envelope file
+        path: PathBuf,
+
+        /// Client key (DSN) to send with (overrides SENTRY_DSN and config)
+        #[arg(long)]
+        dsn: Option<String>,
+    },
+    /// Log in and save an auth token to the selected profile
+    #[command(after_help = "EXAMPLES:
+    sentry login
+    sentry login --device --client-id abc123
+    sentry --profile onprem --server https://sentry.example.com login
+    sentry login --token sntryu_...")]
+    Login {
+        /// Use the OAuth device-code flow instead of pasting a token
+        #[arg(long)]
+        device: bool,
+
+        /// OAuth application client ID for --device
+        #[arg(long, env = "SENTRY_OAUTH_CLIENT_ID", requires = "device")]
+        client_id: Option<String>,
+
+        /// Print the URL instead of opening a browser
+        #[arg(long)]
+        no_browser: bool,
+    },
+    /// Remove the saved auth token from the selected profile
+    #[command(after_help = "EXAMPLES:
+    sentry logout
+    sentry --profile onprem logout")]
+    Logout,
+    /// Inspect authentication
+    #[command(after_help = "EXAMPLES:
+    sentry auth status
+    sentry auth status -O json")]
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommands,
+    },
     /// Manage CLI configuration
-    #[command(alias = "cfg", after_help = "EXAMPLES:
+    #[command(
+        alias = "cfg",
+        after_help = "EXAMPLES:
     sentry config init
     sentry config show
-    sentry config set default_org myorg")]
+    sentry config set default_org myorg"
+    )]
     Config {
         #[command(subcommand)]
         command: ConfigCommands,
@@ -83,21 +271,32 @@ pub enum Commands {
 #[derive(Subcommand)]
 pub enum IssuesCommands {
     /// List issues with optional filtering
-    #[command(alias = "ls", after_help = "EXAMPLES:
+    #[command(
+        alias = "ls",
+        after_help = "EXAMPLES:
     sentry issues list
     sentry issues list --project myproject --status unresolved
-    sentry issues list --query \"is:unresolved\" --limit 100")]
+    sentry issues list --all-projects --query \"is:unresolved\" --limit 100
+    sentry issues list --max-results 500 --output json
+    sentry issues list --fields id,short_id,title,level,assignee,users,first_seen,project
+    sentry issues list --cursor \"1700000000000:0:0\"
+    sentry issues list --watch --interval 1m --notify"
+    )]
     List {
-        /// Filter by project slug(s), comma-separated
+        /// Filter by project slug(s), comma-separated (default: SENTRY_PROJECT or default_project)
         #[arg(long, short)]
         project: Option<String>,
 
+        /// List issues of all projects, ignoring the default project
+        #[arg(long, conflicts_with = "project")]
+        all_projects: bool,
+
         /// Filter by status: unresolved, resolved, ignored
         #[arg(long, short)]
         status: Option<String>,
 
         /// Sentry search query string
-        #[arg(long, short)]
+        #[arg(long)]
         query: Option<String>,
 
         /// Sort by: date, new, freq, user
@@ -111,21 +310,104 @@ pub enum IssuesCommands {
         /// Fetch all pages (may be slow for large result sets)
         #[arg(long)]
         all: bool,
+
+        /// Stop after this many issues, fetching as many pages of --limit as needed
+        #[arg(long)]
+        max_results: Option<usize>,
+
+        /// Start at this page cursor, as printed by an earlier list
+        #[arg(long, conflicts_with = "watch")]
+        cursor: Option<String>,
+
+        /// Columns of the table, CSV, TSV or Markdown: comma-separated fields of an issue (default: issue_fields config or id,short_id,title,status,count,last_seen)
+        #[arg(long)]
+        fields: Option<String>,
+
+        /// Show text in full instead of fitting the table to the terminal
+        #[arg(long)]
+        no_truncate: bool,
+
+        /// Poll and redraw the table, highlighting new issues and rising event counts
+        #[arg(long)]
+        watch: bool,
+
+        /// Time between polls in watch mode, e.g. 30s, 5m
+        #[arg(long, default_value = "30s", value_parser = parse_interval, requires = "watch")]
+        interval: Duration,
+
+        /// Ring the terminal bell when issues appear or event counts rise
+        #[arg(long, requires = "watch")]
+        bell: bool,
+
+        /// Show a desktop notification when issues appear or event counts rise
+        #[arg(long, requires = "watch")]
+        notify: bool,
+    },
+
+    /// Triage issues in an interactive full-screen view
+    #[command(after_help = "EXAMPLES:
+    sentry issues triage
+    sentry issues triage --project myproject --query \"is:unassigned\"
+    sentry issues triage --all-projects --sort freq --limit 100
+
+KEYS:
+    Enter details and events, Space mark, r resolve, u unresolve, i ignore,
+    a assign, m merge marked, o open in browser, ? help, q quit")]
+    Triage {
+        /// Filter by project slug(s), comma-separated (default: SENTRY_PROJECT or default_project)
+        #[arg(long, short)]
+        project: Option<String>,
+
+        /// Triage issues of all projects, ignoring the default project
+        #[arg(long, conflicts_with = "project")]
+        all_projects: bool,
+
+        /// Filter by status: unresolved, resolved, ignored
+        #[arg(long, short, default_value = "unresolved")]
+        status: String,
+
+        /// Sentry search query string
+        #[arg(long)]
+        query: Option<String>,
+
+        /// Sort by: date, new, freq, user
+        #[arg(long, default_value = "date")]
+        sort: String,
+
+        /// Maximum number of issues to load
+        #[arg(long, default_value = "50")]
+        limit: u32,
     },
 
     /// View detailed issue information
-    #[command(alias = "show", alias = "v", after_help = "EXAMPLES:
+    #[command(
+        alias = "show",
+        alias = "v",
+        after_help = "EXAMPLES:
     sentry issues view ISSUE-123
-    sentry issues view 12345678")]
+    sentry issues view 12345678"
+    )]
     View {
         /// Issue ID or short ID
         issue_id: String,
     },
 
+    /// List events for an issue
+    #[command(after_help = "EXAMPLES:
+    sentry issues events ISSUE-123
+    sentry issues events ISSUE-123 --query \"environment:production\" --limit 10")]
+    Events {
+        #[command(flatten)]
+        args: ListEventsArgs,
+    },
+
     /// Resolve one or more issues
-    #[command(alias = "r", after_help = "EXAMPLES:
+    #[command(
+        alias = "r",
+        after_help = "EXAMPLES:
     sentry issues resolve ISSUE-123
-    sentry issues resolve ISSUE-123 ISSUE-456 --in-next-release")]
+    sentry issues resolve ISSUE-123 ISSUE-456 --in-next-release"
+    )]
     Resolve {
         /// Issue ID(s) to resolve
         #[arg(required = true)]
@@ -150,10 +432,13 @@ pub enum IssuesCommands {
     },
 
     /// Assign issue(s) to a user or team
-    #[command(alias = "a", after_help = "EXAMPLES:
+    #[command(
+        alias = "a",
+        after_help = "EXAMPLES:
     sentry issues assign ISSUE-123 --to user@example.com
     sentry issues assign ISSUE-123 --to team:backend
-    sentry issues assign ISSUE-123 --unassign")]
+    sentry issues assign ISSUE-123 --unassign"
+    )]
     Assign {
         /// Issue ID(s) to assign
         #[arg(required = true)]
@@ -217,6 +502,619 @@ pub enum IssuesCommands {
     },
 }
 
+#[derive(Args)]
+pub struct ListEventsArgs {
+    /// Issue ID or short ID
+    pub issue_id: String,
+
+    /// Sentry search query to filter events
+    #[arg(long)]
+    pub query: Option<String>,
+
+    /// Maximum number of events to show, fetching further pages as needed
+    #[arg(long, default_value = "25")]
+    pub limit: usize,
+
+    /// Include full event payloads (entries, contexts) in JSON output
+    #[arg(long)]
+    pub full: bool,
+}
+
+#[derive(Subcommand)]
+pub enum EventsCommands {
+    /// List events for an issue
+    #[command(
+        alias = "ls",
+        after_help = "EXAMPLES:
+    sentry events list ISSUE-123
+    sentry events list ISSUE-123 --limit 5"
+    )]
+    List {
+        #[command(flatten)]
+        args: ListEventsArgs,
+    },
+
+    /// View an event with its stack trace
+    #[command(
+        alias = "show",
+        after_help = "EXAMPLES:
+    sentry events view ISSUE-123
+    sentry events view ISSUE-123 oldest
+    sentry events view ISSUE-123 9fac2ceed9344f2bbfdd1fdacb0ed9b1"
+    )]
+    View {
+        /// Issue ID or short ID
+        issue_id: String,
+
+        /// Event ID, or "latest" / "oldest"
+        #[arg(default_value = "latest")]
+        event: String,
+
+        /// Only show in-app frames
+        #[arg(long)]
+        in_app: bool,
+
+        /// Number of most recent breadcrumbs to show
+        #[arg(long, default_value = "10")]
+        breadcrumbs: usize,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ReleasesCommands {
+    /// Create a new release
+    #[command(after_help = "EXAMPLES:
+    sentry releases new 1.2.3 --project backend
+    sentry releases new 1.2.3 --project backend,frontend --finalize")]
+    New {
+        /// Release version
+        version: String,
+
+        /// Project slug(s) the release belongs to, comma-separated (default: SENTRY_PROJECT or default_project)
+        #[arg(long, short, value_delimiter = ',')]
+        project: Vec<String>,
+
+        /// URL pointing to the release (e.g. a changelog or CI build)
+        #[arg(long)]
+        url: Option<String>,
+
+        /// Git ref of the release (commit SHA or tag)
+        #[arg(long = "ref")]
+        git_ref: Option<String>,
+
+        /// Mark the release as released immediately
+        #[arg(long)]
+        finalize: bool,
+    },
+
+    /// Mark a release as released
+    #[command(after_help = "EXAMPLES:
+    sentry releases finalize 1.2.3
+    sentry releases finalize 1.2.3 --released 2024-05-01T12:00:00Z")]
+    Finalize {
+        /// Release version
+        version: String,
+
+        /// Release timestamp in RFC 3339 format (default: now)
+        #[arg(long)]
+        released: Option<String>,
+    },
+
+    /// List releases
+    #[command(
+        alias = "ls",
+        after_help = "EXAMPLES:
+    sentry releases list
+    sentry releases list --project backend --query 1.2
+    sentry releases list --all-projects"
+    )]
+    List {
+        /// Only list releases of this project (default: SENTRY_PROJECT or default_project)
+        #[arg(long, short)]
+        project: Option<String>,
+
+        /// List releases of all projects, ignoring the default project
+        #[arg(long, conflicts_with = "project")]
+        all_projects: bool,
+
+        /// Filter releases by version substring
+        #[arg(long)]
+        query: Option<String>,
+
+        /// Maximum number of results
+        #[arg(long, default_value = "25")]
+        limit: u32,
+    },
+
+    /// Show release details
+    #[command(
+        alias = "view",
+        after_help = "EXAMPLES:
+    sentry releases info 1.2.3"
+    )]
+    Info {
+        /// Release version
+        version: String,
+    },
+
+    /// Associate commits with a release
+    #[command(after_help = "EXAMPLES:
+    sentry releases set-commits 1.2.3 --auto
+    sentry releases set-commits 1.2.3 --commit my-org/my-repo@a1b2c3d
+    sentry releases set-commits 1.2.3 --commit my-org/my-repo@v1.2.2..v1.2.3
+    sentry releases set-commits 1.2.3 --clear")]
+    SetCommits {
+        /// Release version
+        version: String,
+
+        /// Read commits from the local git repository, since the previous release
+        #[arg(long, conflicts_with_all = ["commit", "clear"])]
+        auto: bool,
+
+        /// Commit range as REPO@REV or REPO@PREV..REV, resolved by a repository integration
+        #[arg(long, conflicts_with = "clear")]
+        commit: Vec<String>,
+
+        /// Remove all commits from the release
+        #[arg(long)]
+        clear: bool,
+
+        /// Git remote used to determine the repository name with --auto
+        #[arg(long, default_value = "origin")]
+        remote: String,
+
+        /// Number of commits to associate when there is no previous release
+        #[arg(long, default_value = "20")]
+        initial_depth: usize,
+    },
+
+    /// Delete a release
+    #[command(after_help = "EXAMPLES:
+    sentry releases delete 1.2.3 --confirm")]
+    Delete {
+        /// Release version
+        version: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Manage deploys of a release
+    #[command(after_help = "EXAMPLES:
+    sentry releases deploys 1.2.3 new --env production
+    sentry releases deploys 1.2.3 list")]
+    Deploys {
+        /// Release version
+        version: String,
+
+        #[command(subcommand)]
+        command: DeploysCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DeploysCommands {
+    /// Record a new deploy
+    #[command(after_help = "EXAMPLES:
+    sentry releases deploys 1.2.3 new --env production
+    sentry releases deploys 1.2.3 new --env staging --name build-512 --url https://ci.example.com/512
+    sentry releases deploys 1.2.3 new --env production --started 2024-05-01T12:00:00Z")]
+    New {
+        /// Environment the release was deployed to
+        #[arg(long, short)]
+        env: String,
+
+        /// Optional human-readable name of the deploy
+        #[arg(long, short)]
+        name: Option<String>,
+
+        /// URL pointing to the deploy (e.g. a CI job)
+        #[arg(long, short)]
+        url: Option<String>,
+
+        /// Deploy start time in RFC 3339 format
+        #[arg(long)]
+        started: Option<String>,
+
+        /// Deploy finish time in RFC 3339 format (default: now)
+        #[arg(long)]
+        finished: Option<String>,
+
+        /// Only record the deploy for these project slug(s), comma-separated
+        #[arg(long, short, value_delimiter = ',')]
+        project: Vec<String>,
+    },
+
+    /// List deploys of a release
+    #[command(
+        alias = "ls",
+        after_help = "EXAMPLES:
+    sentry releases deploys 1.2.3 list"
+    )]
+    List,
+}
+
+#[derive(Subcommand)]
+pub enum SourcemapsCommands {
+    /// Inject debug IDs into minified JavaScript files and their source maps
+    #[command(after_help = "EXAMPLES:
+    sentry sourcemaps inject ./dist
+    sentry sourcemaps inject ./dist/app.min.js --dry-run")]
+    Inject {
+        /// Files or directories to process
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+
+        /// Show what would be changed without modifying files
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Upload JavaScript files and source maps as an artifact bundle
+    #[command(after_help = "EXAMPLES:
+    sentry sourcemaps upload ./dist --project frontend
+    sentry sourcemaps upload ./dist --project frontend --release 1.2.3 --url-prefix '~/static/js'")]
+    Upload {
+        /// Files or directories to upload
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+
+        /// Project slug(s) to associate the bundle with, comma-separated (default: SENTRY_PROJECT or default_project)
+        #[arg(long, short, value_delimiter = ',')]
+        project: Vec<String>,
+
+        /// Associate the bundle with a release (for files without debug IDs)
+        #[arg(long)]
+        release: Option<String>,
+
+        /// Distribution identifier within the release
+        #[arg(long)]
+        dist: Option<String>,
+
+        /// URL prefix prepended to each file's path relative to the scanned directory
+        #[arg(long, default_value = "~/")]
+        url_prefix: String,
+
+        /// Wait for the server to finish processing the bundle
+        #[arg(long)]
+        wait: bool,
+
+        /// Give up waiting after this long, e.g. 90s or 10m
+        #[arg(long, default_value = "10m", value_parser = parse_interval, requires = "wait")]
+        wait_timeout: Duration,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ProjectsCommands {
+    /// List projects in the organization
+    #[command(after_help = "EXAMPLES:
+    sentry projects list
+    sentry projects list --output json")]
+    List,
+
+    /// Show project details
+    #[command(after_help = "EXAMPLES:
+    sentry projects view backend")]
+    View {
+        /// Project slug
+        project: String,
+    },
+
+    /// Create a project and print its DSN
+    #[command(after_help = "EXAMPLES:
+    sentry projects create backend --team platform
+    sentry projects create \"Web Frontend\" --team web --slug frontend --platform javascript-react")]
+    Create {
+        /// Project name
+        name: String,
+
+        /// Team that owns the project
+        #[arg(long, short)]
+        team: String,
+
+        /// Project slug (derived from the name by default)
+        #[arg(long)]
+        slug: Option<String>,
+
+        /// Platform, e.g. python, javascript-react, rust
+        #[arg(long)]
+        platform: Option<String>,
+    },
+
+    /// Delete a project
+    #[command(after_help = "EXAMPLES:
+    sentry projects delete backend --confirm")]
+    Delete {
+        /// Project slug
+        project: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Manage client keys (DSNs) of a project
+    #[command(after_help = "EXAMPLES:
+    sentry projects keys list backend
+    sentry projects keys create backend --name ci")]
+    Keys {
+        #[command(subcommand)]
+        command: ProjectKeysCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ProjectKeysCommands {
+    /// List client keys of a project
+    #[command(after_help = "EXAMPLES:
+    sentry projects keys list backend")]
+    List {
+        /// Project slug
+        project: String,
+    },
+
+    /// Create a client key
+    #[command(after_help = "EXAMPLES:
+    sentry projects keys create backend --name ci")]
+    Create {
+        /// Project slug
+        project: String,
+
+        /// Key name
+        #[arg(long)]
+        name: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum OrgsCommands {
+    /// List organizations you are a member of
+    #[command(after_help = "EXAMPLES:
+    sentry orgs list
+    sentry orgs list --output json")]
+    List,
+
+    /// Show organization details
+    #[command(after_help = "EXAMPLES:
+    sentry orgs view
+    sentry orgs view my-org")]
+    View {
+        /// Organization slug (default: the configured organization)
+        org: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TeamsCommands {
+    /// List teams in the organization
+    #[command(after_help = "EXAMPLES:
+    sentry teams list")]
+    List,
+
+    /// Create a team
+    #[command(after_help = "EXAMPLES:
+    sentry teams create platform
+    sentry teams create web --name \"Web Frontend\"")]
+    Create {
+        /// Team slug
+        slug: String,
+
+        /// Display name (defaults to the slug)
+        #[arg(long)]
+        name: Option<String>,
+    },
+
+    /// List members of a team
+    #[command(after_help = "EXAMPLES:
+    sentry teams members platform")]
+    Members {
+        /// Team slug
+        team: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DebugFilesCommands {
+    /// Show debug information of a file
+    #[command(after_help = "EXAMPLES:
+    sentry debug-files check ./target/release/myapp
+    sentry debug-files check ./symbols/myapp.sym")]
+    Check {
+        /// Path to the file
+        path: PathBuf,
+    },
+
+    /// Search directories for debug files with the given debug IDs
+    #[command(after_help = "EXAMPLES:
+    sentry debug-files find 3249d99d-0c40-4931-8610-f4e4fb0b6936
+    sentry debug-files find 3249D99D0C4049318610F4E4FB0B69361 --path ./build --path ./symbols")]
+    Find {
+        /// Debug IDs to look for (UUID or Breakpad format)
+        #[arg(required = true)]
+        ids: Vec<String>,
+
+        /// Directories to search (default: current directory)
+        #[arg(long, default_value = ".")]
+        path: Vec<PathBuf>,
+    },
+
+    /// Upload debug files found in the given paths
+    #[command(after_help = "EXAMPLES:
+    sentry debug-files upload ./target/release --project native
+    sentry debug-files upload ./build ./symbols --project native --wait")]
+    Upload {
+        /// Files or directories to scan
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+
+        /// Project slug to upload to (default: SENTRY_PROJECT or default_project)
+        #[arg(long, short)]
+        project: Option<String>,
+
+        /// Wait for the server to finish processing the files
+        #[arg(long)]
+        wait: bool,
+
+        /// Give up waiting after this long, e.g. 90s or 10m
+        #[arg(long, default_value = "10m", value_parser = parse_interval, requires = "wait")]
+        wait_timeout: Duration,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CheckInStatusArg {
+    InProgress,
+    Ok,
+    Error,
+}
+
+/// Monitor configuration to create or update along with a check-in
+#[derive(Args)]
+pub struct MonitorConfigArgs {
+    /// Schedule as a crontab expression ("0 3 * * *") or interval ("10 minutes").
+    /// Creates or updates the monitor.
+    #[arg(long)]
+    pub schedule: Option<String>,
+
+    /// Minutes after the expected time before a check-in is considered missed
+    #[arg(long, requires = "schedule")]
+    pub checkin_margin: Option<u32>,
+
+    /// Minutes a check-in may stay in progress before it is marked as timed out
+    #[arg(long, requires = "schedule")]
+    pub max_runtime: Option<u32>,
+
+    /// Timezone the crontab schedule is evaluated in (e.g. Europe/Berlin)
+    #[arg(long, requires = "schedule")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum MonitorsCommands {
+    /// List cron monitors in the organization
+    #[command(after_help = "EXAMPLES:
+    sentry monitors list
+    sentry monitors list --output json")]
+    List,
+
+    /// Show monitor details and recent check-ins
+    #[command(after_help = "EXAMPLES:
+    sentry monitors view nightly-backup
+    sentry monitors view nightly-backup --limit 50")]
+    View {
+        /// Monitor slug
+        monitor: String,
+
+        /// Number of recent check-ins to show
+        #[arg(long, default_value = "10")]
+        limit: u32,
+    },
+
+    /// Run a command and report its outcome as check-ins
+    #[command(after_help = "EXAMPLES:
+    sentry monitors run nightly-backup -- ./backup.sh
+    sentry monitors run nightly-backup --env production -- pg_dump mydb -f /backups/db.sql
+    sentry monitors run nightly-backup --schedule '0 3 * * *' --max-runtime 60 -- ./backup.sh")]
+    Run {
+        /// Monitor slug
+        monitor: String,
+
+        /// Environment to report the check-ins for
+        #[arg(long = "env", short = 'e')]
+        environment: Option<String>,
+
+        #[command(flatten)]
+        config: MonitorConfigArgs,
+
+        /// Command to run, followed by its arguments
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+
+    /// Send a single check-in
+    #[command(after_help = "EXAMPLES:
+    sentry monitors checkin nightly-backup --status in-progress
+    sentry monitors checkin nightly-backup --status ok --id <CHECKIN_ID> --duration 5400
+    sentry monitors checkin nightly-backup --status error --env staging")]
+    Checkin {
+        /// Monitor slug
+        monitor: String,
+
+        /// Check-in status
+        #[arg(long, value_enum)]
+        status: CheckInStatusArg,
+
+        /// Update an existing check-in (e.g. one started with --status in-progress)
+        #[arg(long)]
+        id: Option<String>,
+
+        /// Duration of the job in milliseconds
+        #[arg(long)]
+        duration: Option<u64>,
+
+        /// Environment to report the check-in for
+        #[arg(long = "env", short = 'e', conflicts_with = "id")]
+        environment: Option<String>,
+
+        #[command(flatten)]
+        config: MonitorConfigArgs,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum EventLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
+    Fatal,
+}
+
+#[derive(Args)]
+pub struct SendEventArgs {
+    /// Event message. Repeat to send a multi-line message.
+    #[arg(long, short, required = true)]
+    pub message: Vec<String>,
+
+    /// Event severity
+    #[arg(long, short, value_enum, default_value = "error")]
+    pub level: EventLevel,
+
+    /// Tag as KEY:VALUE (repeatable)
+    #[arg(long, short)]
+    pub tag: Vec<String>,
+
+    /// Extra data as KEY:VALUE (repeatable)
+    #[arg(long, short)]
+    pub extra: Vec<String>,
+
+    /// Fingerprint component for grouping (repeatable)
+    #[arg(long, short)]
+    pub fingerprint: Vec<String>,
+
+    /// Release version
+    #[arg(long, short)]
+    pub release: Option<String>,
+
+    /// Environment
+    #[arg(long = "env", short = 'E')]
+    pub environment: Option<String>,
+
+    /// Client key (DSN) to send with (overrides SENTRY_DSN and config)
+    #[arg(long)]
+    pub dsn: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommands {
+    /// Show who the current auth token belongs to and where it comes from
+    #[command(after_help = "EXAMPLES:
+    sentry auth status")]
+    Status,
+}
+
 #[derive(Subcommand)]
 pub enum ConfigCommands {
     /// Create default config file
@@ -232,11 +1130,129 @@ pub enum ConfigCommands {
     /// Set a configuration value
     #[command(after_help = "EXAMPLES:
     sentry config set default_org myorg
-    sentry config set auth_token sk-...")]
+    sentry config set auth_token sntryu_...
+    sentry config set token_storage encrypted
+    sentry config set max_retries 5
+    sentry config set dsn https://key@o0.ingest.sentry.io/42
+    sentry --profile onprem config set server_url https://sentry.example.com")]
     Set {
         /// Configuration key
         key: String,
         /// Configuration value
         value: String,
     },
+
+    /// Print the effective value of a configuration key
+    #[command(after_help = "EXAMPLES:
+    sentry config get default_org
+    sentry --profile onprem config get server_url")]
+    Get {
+        /// Configuration key
+        key: String,
+    },
+
+    /// Remove a configuration value
+    #[command(after_help = "EXAMPLES:
+    sentry config unset default_project
+    sentry --profile onprem config unset auth_token")]
+    Unset {
+        /// Configuration key
+        key: String,
+    },
+
+    /// List all configuration keys with their values and origins
+    #[command(after_help = "EXAMPLES:
+    sentry config list
+    sentry config list --json")]
+    List {
+        /// Output as JSON (same as -O json)
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Manage named configuration profiles
+    #[command(after_help = "EXAMPLES:
+    sentry config profiles list
+    sentry config profiles add onprem --server-url https://sentry.example.com --default-org acme
+    sentry config profiles use onprem")]
+    Profiles {
+        #[command(subcommand)]
+        command: ProfilesCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ProfilesCommands {
+    /// List profiles; the active one is marked with *
+    #[command(after_help = "EXAMPLES:
+    sentry config profiles list")]
+    List,
+
+    /// Make a profile the default for subsequent commands
+    #[command(after_help = "EXAMPLES:
+    sentry config profiles use onprem
+    sentry config profiles use default")]
+    Use {
+        /// Profile name ("default" for the top-level settings)
+        name: String,
+    },
+
+    /// Add a profile
+    #[command(after_help = "EXAMPLES:
+    sentry config profiles add onprem --server-url https://sentry.example.com --default-org acme --auth-token sntrys_...")]
+    Add {
+        /// Profile name
+        name: String,
+
+        /// Sentry server URL
+        #[arg(long)]
+        server_url: Option<String>,
+
+        /// Default organization slug
+        #[arg(long = "default-org")]
+        default_org: Option<String>,
+
+        /// Default project slug
+        #[arg(long)]
+        default_project: Option<String>,
+
+        /// Auth token
+        #[arg(long = "auth-token")]
+        auth_token: Option<String>,
+    },
+
+    /// Remove a profile
+    #[command(after_help = "EXAMPLES:
+    sentry config profiles remove onprem")]
+    Remove {
+        /// Profile name
+        name: String,
+    },
+}
+
+/// Parse an interval such as `30s`, `5m` or `1h`; a bare number is seconds
+fn parse_interval(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid interval '{}'. Use e.g. 30s, 5m or 1h", value))?;
+    let multiplier = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        _ => return Err(format!("invalid unit '{}'. Use s, m or h", unit)),
+    };
+    let seconds = number
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("interval '{}' is too long", value))?;
+
+    if seconds == 0 {
+        return Err("interval must be at least 1s".to_string());
+    }
+    Ok(Duration::from_secs(seconds))
 }
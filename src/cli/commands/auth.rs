@@ -0,0 +1,236 @@
+use crate::api::models::{AuthInfo, Organization};
+use crate::api::{OAuthClient, SentryClient};
+use crate::cli::commands::config::profile_not_found;
+use crate::config::secrets::TokenSource;
+use crate::config::{load_config, save_config, Config, DEFAULT_PROFILE};
+use crate::error::{Result, SentryCliError};
+use crate::output::{is_data_output, print_auth_status, print_data, print_success};
+use serde_json::json;
+use std::io::{self, IsTerminal};
+
+/// Scopes requested by the device-code flow: enough for every command
+const DEVICE_SCOPES: &[&str] = &[
+    "org:read",
+    "project:read",
+    "project:write",
+    "project:releases",
+    "team:read",
+    "team:write",
+    "member:read",
+    "event:read",
+    "event:write",
+];
+
+pub struct LoginOptions {
+    pub server: Option<String>,
+    pub token: Option<String>,
+    pub device: bool,
+    pub client_id: Option<String>,
+    pub no_browser: bool,
+    pub verbose: bool,
+}
+
+pub async fn login(profile: Option<&str>, options: LoginOptions) -> Result<()> {
+    let mut config = load_config()?;
+    let name = config.get_profile(profile);
+    let mut settings = match &name {
+        Some(name) => config.profiles.get(name).cloned().unwrap_or_default(),
+        None => config.settings.clone(),
+    };
+    if options.server.is_some() {
+        settings.server_url = options.server.clone();
+    }
+
+    let mut effective = Config::default();
+    effective.settings = settings.clone().or(config.settings.clone());
+    // The token is the one about to be entered
+    let server_url = effective.get_server_url(options.server.as_deref(), &TokenSource::Flag);
+
+    let token = match &options.token {
+        Some(token) => token.clone(),
+        None if options.device => device_login(&server_url, &options).await?,
+        None => prompt_token(&server_url, options.no_browser)?,
+    };
+
+    let client = SentryClient::new(
+        &effective,
+        None,
+        Some(&server_url),
+        Some(&token),
+        None,
+        options.verbose,
+    )?;
+    let (info, orgs) = validate(&client).await.map_err(|e| match e {
+        SentryCliError::Auth(message) => {
+            SentryCliError::Auth(format!("{} rejected the token: {}", server_url, message))
+        }
+        e => e,
+    })?;
+
+    let profile_name = name.as_deref().unwrap_or(DEFAULT_PROFILE);
+    let storage = settings.store_auth_token(profile_name, &token)?;
+    if settings.default_org.is_none() {
+        if let [org] = orgs.as_slice() {
+            settings.default_org = Some(org.slug.clone());
+        }
+    }
+
+    match &name {
+        Some(name) => {
+            config.profiles.insert(name.clone(), settings);
+        }
+        None => config.settings = settings,
+    }
+    save_config(&config)?;
+
+    let source = TokenSource::Stored(storage).to_string();
+    if is_data_output() {
+        print_data(&status_json(
+            &server_url,
+            profile_name,
+            &source,
+            &info,
+            &orgs,
+        ))?;
+    } else {
+        print_auth_status(&server_url, profile_name, &source, &info, &orgs);
+        print_success(&format!(
+            "Saved auth token to profile '{}' (stored in {})",
+            profile_name, source
+        ));
+    }
+
+    Ok(())
+}
+
+/// Obtain a token through the OAuth device authorization flow
+async fn device_login(server_url: &str, options: &LoginOptions) -> Result<String> {
+    let client_id = options.client_id.as_deref().ok_or_else(|| {
+        SentryCliError::Validation(
+            "--device requires an OAuth client ID. Pass --client-id or set SENTRY_OAUTH_CLIENT_ID"
+                .to_string(),
+        )
+    })?;
+
+    let client = OAuthClient::new(server_url, client_id, options.verbose)?;
+    let authorization = client.request_device_code(DEVICE_SCOPES).await?;
+
+    eprintln!(
+        "Open {} and enter the code: {}",
+        authorization.verification_uri, authorization.user_code
+    );
+    if !options.no_browser {
+        let url = authorization
+            .verification_uri_complete
+            .as_deref()
+            .unwrap_or(&authorization.verification_uri);
+        open_browser(url);
+    }
+    eprintln!("Waiting for authorization...");
+
+    client.wait_for_token(&authorization).await
+}
+
+/// Send the user to the token creation page and read the pasted token
+fn prompt_token(server_url: &str, no_browser: bool) -> Result<String> {
+    let url = url::Url::parse(server_url)?.join("/settings/account/api/auth-tokens/")?;
+
+    eprintln!("Create a personal auth token at {}", url);
+    if !no_browser && io::stdin().is_terminal() {
+        open_browser(url.as_str());
+    }
+
+    let token = if io::stdin().is_terminal() {
+        rpassword::prompt_password("Paste your auth token: ")?
+    } else {
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        input
+    };
+
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(SentryCliError::Auth("Auth token is required".to_string()));
+    }
+    Ok(token.to_string())
+}
+
+fn open_browser(url: &str) {
+    if let Err(e) = open::that_detached(url) {
+        eprintln!("Could not open a browser: {}", e);
+    }
+}
+
+/// Check a token against the API root and list the organizations it can
+/// reach. Organization tokens cannot list organizations, which is not an
+/// error.
+async fn validate(client: &SentryClient) -> Result<(AuthInfo, Vec<Organization>)> {
+    let info = client.get_auth_info().await?;
+    if info.user.is_none() && info.auth.is_none() {
+        return Err(SentryCliError::Auth("The token is not valid".to_string()));
+    }
+
+    let orgs = match client.list_organizations().await {
+        Ok(orgs) => orgs,
+        Err(e) => {
+            eprintln!("Could not list organizations: {}", e);
+            Vec::new()
+        }
+    };
+
+    Ok((info, orgs))
+}
+
+pub fn logout(profile: Option<&str>) -> Result<()> {
+    let mut config = load_config()?;
+    let name = config.get_profile(profile);
+    let settings = match &name {
+        Some(name) => config
+            .profiles
+            .get_mut(name)
+            .ok_or_else(|| profile_not_found(name))?,
+        None => &mut config.settings,
+    };
+    let profile_name = name.as_deref().unwrap_or(DEFAULT_PROFILE);
+
+    settings.delete_auth_token(profile_name)?;
+    save_config(&config)?;
+    print_success(&format!("Logged out of profile '{}'", profile_name));
+
+    if std::env::var("SENTRY_AUTH_TOKEN").is_ok() {
+        eprintln!("Note: SENTRY_AUTH_TOKEN is still set in the environment");
+    }
+
+    Ok(())
+}
+
+pub async fn status(client: &SentryClient, profile: &str, source: &TokenSource) -> Result<()> {
+    let (info, orgs) = validate(client).await?;
+    let server = client.server_url().as_str().trim_end_matches('/');
+    let source = source.to_string();
+
+    if is_data_output() {
+        print_data(&status_json(server, profile, &source, &info, &orgs))?;
+    } else {
+        print_auth_status(server, profile, &source, &info, &orgs);
+    }
+
+    Ok(())
+}
+
+fn status_json(
+    server: &str,
+    profile: &str,
+    source: &str,
+    info: &AuthInfo,
+    orgs: &[Organization],
+) -> serde_json::Value {
+    json!({
+        "server": server,
+        "profile": profile,
+        "tokenSource": source,
+        "user": info.user,
+        "scopes": info.scopes(),
+        "organizations": orgs.iter().map(|org| &org.slug).collect::<Vec<_>>(),
+    })
+}
@@ -1,29 +1,45 @@
-use crate::config::{config_path, load_config};
+use crate::api::SentryClient;
+use crate::cli::commands::prompt_confirmation;
+use crate::config::secrets::{self, TokenSource, TokenStorage};
+use crate::config::{
+    check_key, config_path, load_config, save_config, validate, Config, ConfigValue, Settings,
+    DEFAULT_PROFILE, KEYS,
+};
 use crate::error::{Result, SentryCliError};
-use crate::output::print_success;
-use std::fs;
+use crate::output::{
+    is_data_output, print_config_table, print_data, print_json, print_profiles_table, print_success,
+};
+use serde_json::json;
 use std::io::{self, Write};
 
-pub fn init_config() -> Result<()> {
+pub async fn init_config(profile: Option<&str>) -> Result<()> {
     let path = config_path();
-
-    if path.exists() {
-        print!(
-            "Config file already exists at {}. Overwrite? [y/N] ",
-            path.display()
-        );
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-
-        if !input.trim().eq_ignore_ascii_case("y") {
+    let mut config = load_config()?;
+    let profile = config.get_profile(profile);
+    let profile = profile.as_deref();
+
+    let exists = match profile {
+        Some(name) => config.profiles.contains_key(name),
+        None => path.exists(),
+    };
+    if exists {
+        let question = match profile {
+            Some(name) => format!("Profile '{}' already exists. Overwrite?", name),
+            None => format!(
+                "Config file already exists at {}. Overwrite?",
+                path.display()
+            ),
+        };
+        if !prompt_confirmation(&question)? {
             println!("Aborted.");
             return Ok(());
         }
     }
 
-    println!("Sentry CLI Configuration");
+    match profile {
+        Some(name) => println!("Sentry CLI Configuration (profile '{}')", name),
+        None => println!("Sentry CLI Configuration"),
+    }
     println!("========================\n");
 
     // Get auth token
@@ -38,13 +54,28 @@ pub fn init_config() -> Result<()> {
         return Err(SentryCliError::Auth("Auth token is required".to_string()));
     }
 
+    // Get server URL (optional, for self-hosted)
+    print!("Enter Sentry server URL [leave empty for sentry.io]: ");
+    io::stdout().flush()?;
+
+    let mut server_url = String::new();
+    io::stdin().read_line(&mut server_url)?;
+    let server_url = server_url.trim();
+
     // Get default organization (optional)
-    print!("Enter default organization slug [optional]: ");
+    print!("Enter default organization slug [leave empty to choose from a list]: ");
     io::stdout().flush()?;
 
     let mut default_org = String::new();
     io::stdin().read_line(&mut default_org)?;
-    let default_org = default_org.trim();
+    let mut default_org = default_org.trim().to_string();
+
+    if default_org.is_empty() {
+        let server_url = (!server_url.is_empty()).then_some(server_url);
+        default_org = choose_org(server_url, auth_token)
+            .await?
+            .unwrap_or_default();
+    }
 
     // Get default project (optional)
     print!("Enter default project slug [optional]: ");
@@ -54,104 +85,338 @@ pub fn init_config() -> Result<()> {
     io::stdin().read_line(&mut default_project)?;
     let default_project = default_project.trim();
 
-    // Get server URL (optional, for self-hosted)
-    print!("Enter Sentry server URL [leave empty for sentry.io]: ");
-    io::stdout().flush()?;
+    let non_empty = |value: &str| (!value.is_empty()).then(|| value.to_string());
+    let mut settings = Settings {
+        default_org: non_empty(&default_org),
+        default_project: non_empty(default_project),
+        server_url: non_empty(server_url),
+        ..Default::default()
+    };
+    settings.validate()?;
+    validate("auth_token", auth_token)?;
+    let storage = settings.store_auth_token(profile.unwrap_or(DEFAULT_PROFILE), auth_token)?;
+
+    match profile {
+        Some(name) => {
+            config.profiles.insert(name.to_string(), settings);
+        }
+        None => config.settings = settings,
+    }
+    save_config(&config)?;
 
-    let mut server_url = String::new();
-    io::stdin().read_line(&mut server_url)?;
-    let server_url = server_url.trim();
+    println!("\nConfig saved to {}", path.display());
+    println!("Auth token stored in {}", TokenSource::Stored(storage));
+    println!("You can now use 'sentry' commands!");
 
-    // Create parent directory if needed
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
-    }
+    Ok(())
+}
 
-    // Build config content
-    let mut config_content = format!("auth_token = \"{}\"\n", auth_token);
-    if !default_org.is_empty() {
-        config_content.push_str(&format!("default_org = \"{}\"\n", default_org));
-    }
-    if !default_project.is_empty() {
-        config_content.push_str(&format!("default_project = \"{}\"\n", default_project));
-    }
-    if !server_url.is_empty() {
-        config_content.push_str(&format!("server_url = \"{}\"\n", server_url));
+/// Offer the organizations the token has access to. Returns `None` if the
+/// list cannot be fetched or the user skips the choice.
+async fn choose_org(server_url: Option<&str>, auth_token: &str) -> Result<Option<String>> {
+    let config = Config::default();
+    let client = SentryClient::new(&config, None, server_url, Some(auth_token), None, false)?;
+    let orgs = match client.list_organizations().await {
+        Ok(orgs) if !orgs.is_empty() => orgs,
+        Ok(_) => return Ok(None),
+        Err(e) => {
+            eprintln!("Could not list organizations: {}", e);
+            return Ok(None);
+        }
+    };
+
+    println!("\nOrganizations:");
+    for (i, org) in orgs.iter().enumerate() {
+        println!("  {}) {} ({})", i + 1, org.slug, org.name);
     }
 
-    fs::write(&path, config_content)?;
+    loop {
+        print!(
+            "Select default organization [1-{}, empty to skip]: ",
+            orgs.len()
+        );
+        io::stdout().flush()?;
 
-    println!("\nConfig saved to {}", path.display());
-    println!("You can now use 'sentry' commands!");
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        let input = input.trim();
 
-    Ok(())
+        if input.is_empty() {
+            return Ok(None);
+        }
+        match input.parse::<usize>() {
+            Ok(n) if (1..=orgs.len()).contains(&n) => {
+                return Ok(Some(orgs[n - 1].slug.clone()));
+            }
+            _ => println!("Please enter a number between 1 and {}.", orgs.len()),
+        }
+    }
 }
 
-pub fn show_config() -> Result<()> {
-    let config = load_config();
-    let path = config_path();
+pub fn show_config(profile: Option<&str>) -> Result<()> {
+    let raw = load_config()?;
+    let active = raw.get_profile(profile);
+    let config = raw.with_profile(profile)?.with_local_config()?;
 
-    println!("Config file: {}", path.display());
+    println!("Config file: {}", config_path().display());
+    for path in &config.local_files {
+        println!("Local file:  {}", path.display());
+    }
+    println!(
+        "Profile:     {}",
+        active.as_deref().unwrap_or(DEFAULT_PROFILE)
+    );
     println!();
 
-    if let Some(org) = &config.default_org {
-        println!("default_org:     {}", org);
+    for value in config_values(&config)? {
+        let label = format!("{}:", value.key);
+        match (&value.value, &value.origin) {
+            (Some(v), Some(origin)) => println!("{:<16} {} ({})", label, v, origin),
+            (None, Some(reason)) => println!("{:<16} ({})", label, reason),
+            _ if value.key == "auth_token" => println!("{:<16} (not set)", label),
+            _ => {}
+        }
     }
 
-    if let Some(server) = &config.server_url {
-        println!("server_url:      {}", server);
+    Ok(())
+}
+
+/// Effective values of all keys, with the auth token masked
+fn config_values(config: &Config) -> Result<Vec<ConfigValue>> {
+    KEYS.iter()
+        .map(|key| {
+            let mut value = config.value(key)?;
+            if value.key == "auth_token" && value.value.is_some() {
+                value.value = Some("****...".to_string());
+            }
+            Ok(value)
+        })
+        .collect()
+}
+
+pub fn get_config(key: &str, profile: Option<&str>) -> Result<()> {
+    let config = load_config()?.with_profile(profile)?.with_local_config()?;
+
+    // Unlike `show`, errors from the secret store are reported as such
+    let value = if key == "auth_token" {
+        match config.resolve_auth_token(None) {
+            Ok((token, _)) => Some(token),
+            Err(SentryCliError::Auth(_)) => None,
+            Err(e) => return Err(e),
+        }
     } else {
-        println!("server_url:      https://sentry.io (default)");
+        config.value(key)?.value
+    };
+
+    match value {
+        Some(value) => {
+            if is_data_output() {
+                print_data(&json!({ "key": key, "value": value }))?;
+            } else {
+                println!("{}", value);
+            }
+            Ok(())
+        }
+        None => Err(SentryCliError::Config(format!("{} is not set", key))),
     }
+}
+
+pub fn list_config(profile: Option<&str>, json: bool) -> Result<()> {
+    let config = load_config()?.with_profile(profile)?.with_local_config()?;
+    let values = config_values(&config)?;
 
-    if let Some(_token) = &config.auth_token {
-        println!("auth_token:      ****... (set in config)");
-    } else if std::env::var("SENTRY_AUTH_TOKEN").is_ok() {
-        println!("auth_token:      ****... (from SENTRY_AUTH_TOKEN)");
+    if json {
+        print_json(&values)?;
+    } else if is_data_output() {
+        print_data(&values)?;
     } else {
-        println!("auth_token:      (not set)");
+        print_config_table(&values);
     }
 
-    if let Some(project) = &config.default_project {
-        println!("default_project: {}", project);
+    Ok(())
+}
+
+pub fn set_config(key: &str, value: &str, profile: Option<&str>) -> Result<()> {
+    let mut config = load_config()?;
+    let profile = config.get_profile(profile);
+    let name = profile.as_deref().unwrap_or(DEFAULT_PROFILE);
+    let settings = settings_mut(&mut config, profile.as_deref())?;
+
+    match check_key(key)? {
+        "auth_token" => {
+            validate(key, value)?;
+            let storage = settings.store_auth_token(name, value)?;
+            save_config(&config)?;
+            // Don't print sensitive values like auth tokens
+            print_success(&format!(
+                "Updated auth_token (stored in {})",
+                TokenSource::Stored(storage)
+            ));
+            return Ok(());
+        }
+        "token_storage" => migrate_token(settings, name, value.parse()?)?,
+        _ => settings.set(key, value)?,
     }
 
+    save_config(&config)?;
+    print_success(&format!("Updated {} to \"{}\"", key, value));
+
     Ok(())
 }
 
-pub fn set_config(key: &str, value: &str) -> Result<()> {
-    let path = config_path();
-    let mut config = load_config();
-
-    match key {
-        "default_org" => config.default_org = Some(value.to_string()),
-        "server_url" => config.server_url = Some(value.to_string()),
-        "auth_token" => config.auth_token = Some(value.to_string()),
-        "default_project" => config.default_project = Some(value.to_string()),
-        _ => {
-            return Err(SentryCliError::Validation(format!(
-                "Unknown config key: {}. Valid keys: default_org, server_url, auth_token, default_project",
-                key
-            )))
+pub fn unset_config(key: &str, profile: Option<&str>) -> Result<()> {
+    let mut config = load_config()?;
+    let profile = config.get_profile(profile);
+    let name = profile.as_deref().unwrap_or(DEFAULT_PROFILE);
+    let settings = settings_mut(&mut config, profile.as_deref())?;
+
+    match check_key(key)? {
+        "auth_token" => settings.delete_auth_token(name)?,
+        "token_storage" => {
+            if let Some(storage) = settings.token_storage {
+                if secrets::load_token(name, storage)?.is_some() {
+                    return Err(SentryCliError::Validation(format!(
+                        "An auth token is stored in {}. Run 'sentry config unset auth_token' first",
+                        TokenSource::Stored(storage)
+                    )));
+                }
+            }
+            settings.unset(key);
         }
+        key => settings.unset(key),
     }
 
-    // Create parent directory if needed
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
+    save_config(&config)?;
+    print_success(&format!("Unset {}", key));
+
+    Ok(())
+}
+
+/// Move an existing token, including a plaintext one, to a new storage
+fn migrate_token(settings: &mut Settings, profile: &str, storage: TokenStorage) -> Result<()> {
+    let token = match (settings.auth_token.clone(), settings.token_storage) {
+        (Some(token), _) => Some(token),
+        (None, Some(old)) if old != storage => secrets::load_token(profile, old)?,
+        _ => None,
+    };
+
+    let old = settings.token_storage.replace(storage);
+    if let Some(token) = token {
+        settings.store_auth_token(profile, &token)?;
+        if let Some(old) = old.filter(|old| *old != storage) {
+            secrets::delete_token(profile, old)?;
+        }
     }
 
-    let toml_content = toml::to_string_pretty(&config)
-        .map_err(|e| SentryCliError::Config(format!("Failed to serialize config: {}", e)))?;
+    Ok(())
+}
 
-    fs::write(&path, toml_content)?;
+/// The settings `config set` writes to: the profile resolved by
+/// `Config::get_profile`, or the top level
+fn settings_mut<'a>(config: &'a mut Config, profile: Option<&str>) -> Result<&'a mut Settings> {
+    match profile {
+        Some(name) => config
+            .profiles
+            .get_mut(name)
+            .ok_or_else(|| profile_not_found(name)),
+        None => Ok(&mut config.settings),
+    }
+}
 
-    // Don't print sensitive values like auth tokens
-    if key == "auth_token" {
-        print_success(&format!("Updated {}", key));
+pub fn profile_not_found(name: &str) -> SentryCliError {
+    SentryCliError::Config(format!(
+        "Profile '{}' not found. Run 'sentry config profiles list' to see available profiles",
+        name
+    ))
+}
+
+pub fn list_profiles(profile: Option<&str>) -> Result<()> {
+    let config = load_config()?;
+    let active = config
+        .get_profile(profile)
+        .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+
+    let mut profiles = vec![(DEFAULT_PROFILE.to_string(), config.settings.clone())];
+    profiles.extend(config.profiles.clone());
+
+    if is_data_output() {
+        let profiles: Vec<_> = profiles
+            .iter()
+            .map(|(name, settings)| {
+                json!({
+                    "name": name,
+                    "active": *name == active,
+                    "serverUrl": settings.server_url,
+                    "defaultOrg": settings.default_org,
+                    "defaultProject": settings.default_project,
+                    "hasAuthToken": settings.auth_token.is_some() || settings.token_storage.is_some(),
+                })
+            })
+            .collect();
+        print_data(&profiles)?;
     } else {
-        print_success(&format!("Updated {} to \"{}\"", key, value));
+        print_profiles_table(&profiles, &active);
     }
 
     Ok(())
 }
+
+pub fn use_profile(name: &str) -> Result<()> {
+    let mut config = load_config()?;
+
+    if name == DEFAULT_PROFILE {
+        config.active_profile = None;
+    } else if config.profiles.contains_key(name) {
+        config.active_profile = Some(name.to_string());
+    } else {
+        return Err(profile_not_found(name));
+    }
+
+    save_config(&config)?;
+    print_success(&format!("Switched to profile '{}'", name));
+
+    Ok(())
+}
+
+pub fn add_profile(name: &str, mut settings: Settings) -> Result<()> {
+    let mut config = load_config()?;
+
+    if name == DEFAULT_PROFILE || config.profiles.contains_key(name) {
+        return Err(SentryCliError::Validation(format!(
+            "Profile '{}' already exists. Use 'sentry --profile {} config set' to change it",
+            name, name
+        )));
+    }
+
+    settings.validate()?;
+    if let Some(token) = settings.auth_token.clone() {
+        settings.store_auth_token(name, &token)?;
+    }
+    config.profiles.insert(name.to_string(), settings);
+    save_config(&config)?;
+    print_success(&format!(
+        "Added profile '{}'. Activate it with 'sentry config profiles use {}'",
+        name, name
+    ));
+
+    Ok(())
+}
+
+pub fn remove_profile(name: &str) -> Result<()> {
+    let mut config = load_config()?;
+
+    let Some(mut settings) = config.profiles.remove(name) else {
+        return Err(profile_not_found(name));
+    };
+    if let Err(e) = settings.delete_auth_token(name) {
+        eprintln!("Warning: could not remove stored auth token: {}", e);
+    }
+    if config.active_profile.as_deref() == Some(name) {
+        config.active_profile = None;
+    }
+
+    save_config(&config)?;
+    print_success(&format!("Removed profile '{}'", name));
+
+    Ok(())
+}
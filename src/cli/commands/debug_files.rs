@@ -0,0 +1,181 @@
+use crate::api::models::{AssembleDifRequest, ChunkedFileState};
+use crate::api::SentryClient;
+use crate::error::{Result, SentryCliError};
+use crate::output::{
+    is_data_output, print_data, print_dif_detail, print_dif_upload_table, print_difs_table,
+};
+use crate::upload::dif::{self, DifFile, UploadResult, UploadStatus};
+use crate::upload::{upload_missing_chunks, ChunkedFile};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+pub fn check(path: &Path) -> Result<()> {
+    let dif = dif::inspect(path)?.ok_or_else(|| {
+        SentryCliError::Validation(format!(
+            "{} is not a supported debug information file",
+            path.display()
+        ))
+    })?;
+
+    if is_data_output() {
+        print_data(&dif)?;
+    } else {
+        print_dif_detail(&dif);
+    }
+
+    Ok(())
+}
+
+pub fn find(ids: &[String], paths: &[PathBuf]) -> Result<()> {
+    let wanted: Vec<String> = ids.iter().map(|id| dif::normalize_debug_id(id)).collect();
+
+    let found: Vec<DifFile> = dif::scan(paths)?
+        .into_iter()
+        .filter(|d| d.debug_id.as_ref().is_some_and(|id| wanted.contains(id)))
+        .collect();
+
+    let missing: Vec<&String> = wanted
+        .iter()
+        .filter(|id| !found.iter().any(|d| d.debug_id.as_ref() == Some(*id)))
+        .collect();
+
+    if is_data_output() {
+        print_data(&serde_json::json!({
+            "found": found,
+            "missing": missing,
+        }))?;
+    } else {
+        print_difs_table(&found);
+        for id in &missing {
+            eprintln!("Not found: {}", id);
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn upload(
+    client: &SentryClient,
+    paths: &[PathBuf],
+    project: &str,
+    wait: Option<Duration>,
+) -> Result<()> {
+    let mut difs = Vec::new();
+    for dif in dif::scan(paths)? {
+        if dif.debug_id.is_some() {
+            difs.push(dif);
+        } else {
+            eprintln!("Skipping {}: no debug ID", dif.path.display());
+        }
+    }
+
+    if difs.is_empty() {
+        return Err(SentryCliError::Validation(
+            "No debug information files found".to_string(),
+        ));
+    }
+
+    let options = client.get_chunk_upload_options().await?;
+    if !options.accepts("debug_files") {
+        return Err(SentryCliError::Validation(
+            "This Sentry server does not support chunked debug file uploads".to_string(),
+        ));
+    }
+
+    // Identical files (e.g. the same library in several build dirs) are
+    // uploaded once, keyed by content checksum
+    let mut files: BTreeMap<String, (DifFile, ChunkedFile)> = BTreeMap::new();
+    for dif in difs {
+        let chunked = ChunkedFile::new(std::fs::read(&dif.path)?, &options)?;
+        files
+            .entry(chunked.checksum.clone())
+            .or_insert((dif, chunked));
+    }
+
+    let request: BTreeMap<String, AssembleDifRequest> = files
+        .iter()
+        .map(|(checksum, (dif, chunked))| {
+            let request = AssembleDifRequest {
+                name: dif.name(),
+                debug_id: dif.debug_id.clone(),
+                chunks: chunked.chunk_checksums.clone(),
+            };
+            (checksum.clone(), request)
+        })
+        .collect();
+
+    let mut response = client.assemble_difs(project, &request).await?;
+
+    let missing: Vec<String> = response
+        .values()
+        .flat_map(|r| r.missing_chunks.iter().cloned())
+        .collect();
+    // Files the server did not already have; everything else is a no-op
+    let uploaded: Vec<String> = response
+        .iter()
+        .filter(|(_, r)| r.state != ChunkedFileState::Ok)
+        .map(|(checksum, _)| checksum.clone())
+        .collect();
+
+    if !missing.is_empty() {
+        let chunked: Vec<&ChunkedFile> = files.values().map(|(_, c)| c).collect();
+        upload_missing_chunks(client, &options, &chunked, &missing).await?;
+        response = client.assemble_difs(project, &request).await?;
+    }
+
+    if let Some(timeout) = wait {
+        let started = Instant::now();
+        while response.values().any(|r| r.state.is_pending()) {
+            if started.elapsed() >= timeout {
+                return Err(SentryCliError::Processing(format!(
+                    "debug files still processing after {}s",
+                    timeout.as_secs()
+                )));
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            response = client.assemble_difs(project, &request).await?;
+        }
+    }
+
+    let results: Vec<UploadResult> = files
+        .into_iter()
+        .map(|(checksum, (dif, _))| {
+            let assembled = response.remove(&checksum);
+            let state = assembled.as_ref().map(|r| r.state);
+            let status = match state {
+                Some(ChunkedFileState::Error) | None => UploadStatus::Error,
+                _ if !uploaded.contains(&checksum) => UploadStatus::Exists,
+                Some(s) if s.is_pending() => UploadStatus::Processing,
+                _ => UploadStatus::Uploaded,
+            };
+            UploadResult {
+                path: dif.path,
+                kind: dif.kind,
+                debug_id: dif.debug_id.unwrap_or_default(),
+                status,
+                detail: assembled.and_then(|r| r.detail),
+            }
+        })
+        .collect();
+
+    let failed = results
+        .iter()
+        .filter(|r| r.status == UploadStatus::Error)
+        .count();
+
+    if is_data_output() {
+        print_data(&results)?;
+    } else {
+        print_dif_upload_table(&results);
+    }
+
+    if failed > 0 {
+        return Err(SentryCliError::Processing(format!(
+            "{} debug file(s) failed to process",
+            failed
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::api::{for_each_page, Cursors, SentryClient};
+use crate::cli::args::ListEventsArgs;
+use crate::error::Result;
+use crate::output::{
+    events_table_stream, finish_events_table, is_data_output, print_data, print_event_detail,
+    print_events_page, DataStream,
+};
+
+pub struct ViewOptions {
+    pub in_app: bool,
+    pub breadcrumbs: usize,
+}
+
+pub async fn list_events(client: &SentryClient, args: ListEventsArgs) -> Result<()> {
+    let pages = client.issue_event_pages(&args.issue_id, args.query.as_deref(), args.full)?;
+
+    // Print each page as it arrives, fetching more pages until --limit is reached
+    if is_data_output() {
+        let mut out = DataStream::new();
+        let result = for_each_page(pages, Some(args.limit), &mut Cursors::default(), |events| {
+            out.push(&events)
+        })
+        .await;
+        result.and(out.finish())
+    } else {
+        let mut table = events_table_stream();
+        let result = for_each_page(pages, Some(args.limit), &mut Cursors::default(), |events| {
+            print_events_page(&mut table, &events);
+            Ok(())
+        })
+        .await;
+        finish_events_table(table);
+        result
+    }
+}
+
+pub async fn view_event(
+    client: &SentryClient,
+    issue_id: &str,
+    event_id: &str,
+    options: ViewOptions,
+) -> Result<()> {
+    let event = client.get_issue_event(issue_id, event_id).await?;
+
+    if is_data_output() {
+        print_data(&event)?;
+    } else {
+        print_event_detail(&event, options.in_app, options.breadcrumbs);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use crate::api::models::{Issue, IssueStatus};
+use crate::api::SentryClient;
+use crate::error::Result;
+use crate::output::{is_data_output, print_data, print_message, print_success};
+use futures::StreamExt;
+use serde::Serialize;
+
+/// Issues fetched back at once after a bulk change
+const MAX_CONCURRENT_FETCHES: usize = 8;
+
+/// What a command that changes issues did, printed in the data formats
+#[derive(Debug, Serialize)]
+pub(super) struct IssueActionResult {
+    /// resolve, unresolve, ignore, assign, unassign, delete or merge
+    action: &'static str,
+    /// Whether the change was declined at the confirmation prompt
+    cancelled: bool,
+    /// Issues that were changed, as given on the command line
+    ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<IssueStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+    /// Issue the others were merged into
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merged_into: Option<String>,
+    /// The changed issues as they are now; empty for delete
+    issues: Vec<Issue>,
+    /// Changed issues that could not be fetched back
+    fetch_errors: Vec<FetchError>,
+}
+
+#[derive(Debug, Serialize)]
+struct FetchError {
+    id: String,
+    error: String,
+}
+
+impl IssueActionResult {
+    pub(super) fn new(action: &'static str) -> Self {
+        Self {
+            action,
+            cancelled: false,
+            ids: Vec::new(),
+            status: None,
+            assignee: None,
+            merged_into: None,
+            issues: Vec::new(),
+            fetch_errors: Vec::new(),
+        }
+    }
+
+    /// Record that nothing was changed because the user said no
+    pub(super) fn cancelled(mut self) -> Self {
+        self.cancelled = true;
+        self
+    }
+
+    pub(super) fn status(mut self, status: IssueStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub(super) fn assignee(mut self, assignee: Option<String>) -> Self {
+        self.assignee = assignee;
+        self
+    }
+
+    pub(super) fn merged_into(mut self, parent: &str) -> Self {
+        self.merged_into = Some(parent.to_string());
+        self
+    }
+
+    /// Record issues that were changed without being returned, as by delete
+    pub(super) fn changed(mut self, ids: &[String]) -> Self {
+        self.ids.extend(ids.iter().cloned());
+        self
+    }
+
+    /// Record an issue as returned by the update of a single issue
+    pub(super) fn updated(mut self, id: &str, issue: Issue) -> Self {
+        self.ids.push(id.to_string());
+        self.issues.push(issue);
+        self
+    }
+
+    /// Record the issues of a bulk change, which only reports whether the
+    /// request as a whole succeeded, and fetch them back. Issues that cannot
+    /// be fetched, such as IDs that matched nothing, are recorded apart from
+    /// the change.
+    pub(super) async fn fetch(mut self, client: &SentryClient, ids: &[String]) -> Self {
+        let fetched: Vec<Result<Issue>> = futures::stream::iter(ids)
+            .map(|id| client.get_issue(id))
+            .buffered(MAX_CONCURRENT_FETCHES)
+            .collect()
+            .await;
+
+        self.ids.extend(ids.iter().cloned());
+        for (id, result) in ids.iter().zip(fetched) {
+            match result {
+                Ok(issue) => self.issues.push(issue),
+                Err(e) => self.fetch_errors.push(FetchError {
+                    id: id.clone(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+        self
+    }
+
+    /// Number of issues that were changed
+    pub(super) fn count(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Short ID of a changed issue, or `id` itself if the issue is unknown
+    pub(super) fn short_id(&self, id: &str) -> String {
+        self.issues
+            .iter()
+            .find(|issue| issue.id == id)
+            .map_or_else(|| id.to_string(), |issue| issue.short_id.clone())
+    }
+
+    /// Print the result, or `message` in the table formats. Issues that
+    /// could not be fetched back are only warned about, as the change itself
+    /// succeeded.
+    pub(super) fn print(self, message: &str) -> Result<()> {
+        if is_data_output() {
+            return print_data(&self);
+        }
+        if self.cancelled {
+            return print_message(message);
+        }
+
+        if !self.ids.is_empty() {
+            print_success(message);
+        }
+        for error in &self.fetch_errors {
+            eprintln!(
+                "Warning: could not fetch issue {}: {}",
+                error.id, error.error
+            );
+        }
+        Ok(())
+    }
+}
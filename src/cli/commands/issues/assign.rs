@@ -1,7 +1,8 @@
 use crate::api::models::IssueUpdate;
 use crate::api::SentryClient;
 use crate::error::{Result, SentryCliError};
-use crate::output::print_success;
+
+use super::action::IssueActionResult;
 
 pub async fn assign_issues(
     client: &SentryClient,
@@ -22,33 +23,42 @@ pub async fn assign_issues(
         }
     };
 
-    let update = IssueUpdate {
-        assigned_to,
-        ..Default::default()
+    let result = if unassign {
+        IssueActionResult::new("unassign")
+    } else {
+        IssueActionResult::new("assign").assignee(assigned_to.clone())
     };
+    let update = assign_update(assigned_to);
 
     if issue_ids.len() == 1 {
         let issue = client.update_issue(&issue_ids[0], update).await?;
-        if unassign {
-            print_success(&format!("Issue {} unassigned.", issue.short_id));
+        let message = if unassign {
+            format!("Issue {} unassigned.", issue.short_id)
         } else {
             let assignee = issue
                 .assigned_to
-                .map(|a| a.name)
+                .as_ref()
+                .map(|a| a.name.clone())
                 .unwrap_or_else(|| "unknown".to_string());
-            print_success(&format!(
-                "Issue {} assigned to {}.",
-                issue.short_id, assignee
-            ));
-        }
+            format!("Issue {} assigned to {}.", issue.short_id, assignee)
+        };
+        result.updated(&issue_ids[0], issue).print(&message)
     } else {
         client.update_issues(&issue_ids, update).await?;
-        if unassign {
-            print_success(&format!("Unassigned {} issues.", issue_ids.len()));
+        let result = result.fetch(client, &issue_ids).await;
+        let message = if unassign {
+            format!("Unassigned {} issues.", result.count())
         } else {
-            print_success(&format!("Assigned {} issues.", issue_ids.len()));
-        }
+            format!("Assigned {} issues.", result.count())
+        };
+        result.print(&message)
     }
+}
 
-    Ok(())
+/// An update that assigns issues to a user or team; an empty string unassigns
+pub(super) fn assign_update(assigned_to: Option<String>) -> IssueUpdate {
+    IssueUpdate {
+        assigned_to,
+        ..Default::default()
+    }
 }
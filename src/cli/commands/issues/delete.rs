@@ -1,7 +1,8 @@
 use crate::api::SentryClient;
+use crate::cli::commands::prompt_confirmation;
 use crate::error::Result;
-use crate::output::print_success;
-use std::io::{self, Write};
+
+use super::action::IssueActionResult;
 
 pub async fn delete_issues(
     client: &SentryClient,
@@ -9,28 +10,26 @@ pub async fn delete_issues(
     confirm: bool,
 ) -> Result<()> {
     if !confirm {
-        print!(
-            "Are you sure you want to delete {} issue(s)? [y/N]: ",
+        let question = format!(
+            "Are you sure you want to delete {} issue(s)?",
             issue_ids.len()
         );
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-
-        if !input.trim().eq_ignore_ascii_case("y") {
-            println!("Cancelled.");
-            return Ok(());
+        if !prompt_confirmation(&question)? {
+            return IssueActionResult::new("delete")
+                .cancelled()
+                .print("Cancelled.");
         }
     }
 
-    if issue_ids.len() == 1 {
+    let message = if issue_ids.len() == 1 {
         client.delete_issue(&issue_ids[0]).await?;
-        print_success(&format!("Issue {} deleted.", issue_ids[0]));
+        format!("Issue {} deleted.", issue_ids[0])
     } else {
         client.delete_issues(&issue_ids).await?;
-        print_success(&format!("Deleted {} issues.", issue_ids.len()));
-    }
+        format!("Deleted {} issues.", issue_ids.len())
+    };
 
-    Ok(())
+    IssueActionResult::new("delete")
+        .changed(&issue_ids)
+        .print(&message)
 }
@@ -1,7 +1,8 @@
 use crate::api::models::{IssueStatus, IssueUpdate, StatusDetails};
 use crate::api::SentryClient;
 use crate::error::Result;
-use crate::output::print_success;
+
+use super::action::IssueActionResult;
 
 pub async fn ignore_issues(
     client: &SentryClient,
@@ -10,23 +11,8 @@ pub async fn ignore_issues(
     count: Option<u64>,
     until_escalating: bool,
 ) -> Result<()> {
-    let status_details = if duration.is_some() || count.is_some() || until_escalating {
-        Some(StatusDetails {
-            in_release: None,
-            in_next_release: None,
-            ignore_duration: duration,
-            ignore_count: count,
-            ignore_until_escalating: if until_escalating { Some(true) } else { None },
-        })
-    } else {
-        None
-    };
-
-    let update = IssueUpdate {
-        status: Some(IssueStatus::Ignored),
-        status_details,
-        ..Default::default()
-    };
+    let update = ignore_update(duration, count, until_escalating);
+    let result = IssueActionResult::new("ignore").status(IssueStatus::Ignored);
 
     if issue_ids.len() == 1 {
         let issue = client.update_issue(&issue_ids[0], update).await?;
@@ -39,11 +25,36 @@ pub async fn ignore_issues(
         } else {
             String::new()
         };
-        print_success(&format!("Issue {} ignored{}.", issue.short_id, detail));
+        let message = format!("Issue {} ignored{}.", issue.short_id, detail);
+        result.updated(&issue_ids[0], issue).print(&message)
     } else {
         client.update_issues(&issue_ids, update).await?;
-        print_success(&format!("Ignored {} issues.", issue_ids.len()));
+        let result = result.fetch(client, &issue_ids).await;
+        let message = format!("Ignored {} issues.", result.count());
+        result.print(&message)
     }
+}
 
-    Ok(())
+pub(super) fn ignore_update(
+    duration: Option<u64>,
+    count: Option<u64>,
+    until_escalating: bool,
+) -> IssueUpdate {
+    let status_details = if duration.is_some() || count.is_some() || until_escalating {
+        Some(StatusDetails {
+            in_release: None,
+            in_next_release: None,
+            ignore_duration: duration,
+            ignore_count: count,
+            ignore_until_escalating: if until_escalating { Some(true) } else { None },
+        })
+    } else {
+        None
+    };
+
+    IssueUpdate {
+        status: Some(IssueStatus::Ignored),
+        status_details,
+        ..Default::default()
+    }
 }
@@ -1,8 +1,10 @@
-use crate::api::models::{IssueStatus, ListIssuesParams};
-use crate::api::SentryClient;
-use crate::cli::args::OutputFormat;
+use crate::api::models::{Issue, IssueStatus, ListIssuesParams};
+use crate::api::{for_each_page, Cursors, SentryClient};
 use crate::error::Result;
-use crate::output::{get_format, print_issues_json, print_issues_table};
+use crate::output::{
+    finish_issues_table, is_data_output, issues_table_stream, print_cursors, print_issues_page,
+    print_issues_table, DataStream, IssueColumns,
+};
 
 pub struct ListOptions {
     pub project: Option<String>,
@@ -11,42 +13,140 @@ pub struct ListOptions {
     pub sort: String,
     pub limit: u32,
     pub all: bool,
+    /// Stop after this many issues, fetching further pages as needed
+    pub max_results: Option<usize>,
+    /// Page cursor to start from instead of the first page
+    pub cursor: Option<String>,
 }
 
-pub async fn list_issues(client: &SentryClient, options: ListOptions) -> Result<()> {
-    let status_filter = options
-        .status
-        .as_ref()
-        .and_then(|s| match s.to_lowercase().as_str() {
-            "resolved" => Some(IssueStatus::Resolved),
-            "unresolved" => Some(IssueStatus::Unresolved),
-            "ignored" => Some(IssueStatus::Ignored),
-            _ => None,
-        });
-
-    let projects = options
-        .project
-        .map(|p| p.split(',').map(|s| s.trim().to_string()).collect());
-
-    let params = ListIssuesParams {
-        project: projects,
-        query: options.query,
-        status: status_filter,
-        sort: Some(options.sort),
-        limit: Some(options.limit),
-        cursor: None,
+impl ListOptions {
+    /// Whether results are fetched across pages rather than from one request
+    fn paged(&self) -> bool {
+        self.all || self.max_results.is_some()
+    }
+
+    /// All matching issues, across as many pages as the options ask for
+    pub(super) async fn fetch(&self, client: &SentryClient) -> Result<Vec<Issue>> {
+        if !self.paged() {
+            return Ok(client.list_issues(self.params()).await?.items);
+        }
+
+        let mut issues = Vec::new();
+        let pages = client.issue_pages(&self.params())?;
+        for_each_page(pages, self.max_results, &mut Cursors::default(), |page| {
+            issues.extend(page);
+            Ok(())
+        })
+        .await?;
+        Ok(issues)
+    }
+
+    pub(super) fn params(&self) -> ListIssuesParams {
+        let status_filter = self
+            .status
+            .as_ref()
+            .and_then(|s| match s.to_lowercase().as_str() {
+                "resolved" => Some(IssueStatus::Resolved),
+                "unresolved" => Some(IssueStatus::Unresolved),
+                "ignored" => Some(IssueStatus::Ignored),
+                _ => None,
+            });
+
+        let projects = self
+            .project
+            .as_ref()
+            .map(|p| p.split(',').map(|s| s.trim().to_string()).collect());
+
+        ListIssuesParams {
+            project: projects,
+            query: self.query.clone(),
+            status: status_filter,
+            sort: Some(self.sort.clone()),
+            // No need to fetch more per page than will be shown
+            limit: Some(match self.max_results {
+                Some(max) => self.limit.min(u32::try_from(max).unwrap_or(u32::MAX)),
+                None => self.limit,
+            }),
+            cursor: self.cursor.clone(),
+        }
+    }
+}
+
+/// Print the matching issues followed by the cursors to continue from. JSON
+/// and YAML output, and the input of `--jq`, wrap the issues in an object
+/// with `next_cursor` and `prev_cursor` fields; other formats print the
+/// cursors to stderr.
+///
+/// When paging, the cursors are printed even if a page fails, so that a
+/// script can resume after the last page that was shown.
+pub async fn list_issues(
+    client: &SentryClient,
+    options: ListOptions,
+    columns: &IssueColumns,
+) -> Result<()> {
+    if !options.paged() {
+        let page = client.list_issues(options.params()).await?;
+        let cursors = Cursors {
+            next: page.next_cursor,
+            prev: page.prev_cursor,
+        };
+
+        if is_data_output() {
+            let mut out = DataStream::field("issues");
+            push_issues(&mut out, &page.items, columns)?;
+            finish_data(out, &cursors)?;
+        } else {
+            print_issues_table(&page.items, columns);
+            print_cursors(&cursors);
+        }
+        return Ok(());
+    }
+
+    // Print each page as it arrives instead of buffering every issue
+    let pages = client.issue_pages(&options.params())?;
+    let mut cursors = Cursors {
+        next: options.cursor.clone(),
+        prev: None,
     };
 
-    let issues = if options.all {
-        client.list_all_issues(params).await?
+    if is_data_output() {
+        let mut out = DataStream::field("issues");
+        let result = for_each_page(pages, options.max_results, &mut cursors, |issues| {
+            push_issues(&mut out, &issues, columns)
+        })
+        .await;
+        result.and(finish_data(out, &cursors))
     } else {
-        client.list_issues(params).await?
-    };
+        let mut table = issues_table_stream(columns);
+        let result = for_each_page(pages, options.max_results, &mut cursors, |issues| {
+            print_issues_page(&mut table, &issues, columns);
+            Ok(())
+        })
+        .await;
+        finish_issues_table(table);
+        print_cursors(&cursors);
+        result
+    }
+}
 
-    match get_format() {
-        OutputFormat::Json => print_issues_json(&issues),
-        OutputFormat::Table | OutputFormat::Compact => print_issues_table(&issues),
+/// Print the issues, or only their selected fields where the format has
+/// columns
+fn push_issues(out: &mut DataStream, issues: &[Issue], columns: &IssueColumns) -> Result<()> {
+    match columns.records(issues) {
+        Some(records) => out.push(&records),
+        None => out.push(issues),
     }
+}
 
-    Ok(())
+fn finish_data(out: DataStream, cursors: &Cursors) -> Result<()> {
+    if out.has_fields() {
+        out.finish_with(&[
+            ("next_cursor", &cursors.next),
+            ("prev_cursor", &cursors.prev),
+        ])
+    } else {
+        out.finish()?;
+        print_cursors(cursors);
+        Ok(())
+    }
 }
@@ -1,17 +1,27 @@
 use crate::api::SentryClient;
 use crate::error::Result;
-use crate::output::print_success;
+
+use super::action::IssueActionResult;
 
 pub async fn merge_issues(
     client: &SentryClient,
     primary_id: String,
     other_ids: Vec<String>,
 ) -> Result<()> {
-    let merged = client.merge_issues(&primary_id, &other_ids).await?;
-    print_success(&format!(
+    let merged = client.merge_issues(&primary_id, &other_ids).await?.merge;
+
+    // The response only has IDs; the issue merged into is fetched back
+    let result = IssueActionResult::new("merge")
+        .merged_into(&merged.parent)
+        .fetch(client, std::slice::from_ref(&merged.parent))
+        .await
+        .changed(&merged.children);
+
+    let short_id = result.short_id(&merged.parent);
+    let message = format!(
         "Merged {} issue(s) into {}.",
-        other_ids.len(),
-        merged.short_id
-    ));
-    Ok(())
+        merged.children.len(),
+        short_id
+    );
+    result.print(&message)
 }
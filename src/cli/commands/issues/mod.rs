@@ -1,10 +1,13 @@
+mod action;
 mod assign;
 mod delete;
 mod ignore;
 mod list;
 mod merge;
 mod resolve;
+mod triage;
 mod view;
+mod watch;
 
 pub use assign::assign_issues;
 pub use delete::delete_issues;
@@ -12,4 +15,6 @@ pub use ignore::ignore_issues;
 pub use list::{list_issues, ListOptions};
 pub use merge::merge_issues;
 pub use resolve::{resolve_issues, unresolve_issues};
+pub use triage::triage_issues;
 pub use view::view_issue;
+pub use watch::{watch_issues, WatchOptions};
@@ -1,7 +1,8 @@
 use crate::api::models::{IssueStatus, IssueUpdate, StatusDetails};
 use crate::api::SentryClient;
 use crate::error::Result;
-use crate::output::print_success;
+
+use super::action::IssueActionResult;
 
 pub async fn resolve_issues(
     client: &SentryClient,
@@ -9,6 +10,22 @@ pub async fn resolve_issues(
     in_release: Option<String>,
     in_next_release: bool,
 ) -> Result<()> {
+    let update = resolve_update(in_release, in_next_release);
+    let result = IssueActionResult::new("resolve").status(IssueStatus::Resolved);
+
+    if issue_ids.len() == 1 {
+        let issue = client.update_issue(&issue_ids[0], update).await?;
+        let message = format!("Issue {} resolved.", issue.short_id);
+        result.updated(&issue_ids[0], issue).print(&message)
+    } else {
+        client.update_issues(&issue_ids, update).await?;
+        let result = result.fetch(client, &issue_ids).await;
+        let message = format!("Resolved {} issues.", result.count());
+        result.print(&message)
+    }
+}
+
+pub(super) fn resolve_update(in_release: Option<String>, in_next_release: bool) -> IssueUpdate {
     let status_details = if in_release.is_some() || in_next_release {
         Some(StatusDetails {
             in_release,
@@ -21,36 +38,32 @@ pub async fn resolve_issues(
         None
     };
 
-    let update = IssueUpdate {
+    IssueUpdate {
         status: Some(IssueStatus::Resolved),
         status_details,
         ..Default::default()
-    };
-
-    if issue_ids.len() == 1 {
-        let issue = client.update_issue(&issue_ids[0], update).await?;
-        print_success(&format!("Issue {} resolved.", issue.short_id));
-    } else {
-        client.update_issues(&issue_ids, update).await?;
-        print_success(&format!("Resolved {} issues.", issue_ids.len()));
     }
-
-    Ok(())
 }
 
 pub async fn unresolve_issues(client: &SentryClient, issue_ids: Vec<String>) -> Result<()> {
-    let update = IssueUpdate {
-        status: Some(IssueStatus::Unresolved),
-        ..Default::default()
-    };
+    let update = unresolve_update();
+    let result = IssueActionResult::new("unresolve").status(IssueStatus::Unresolved);
 
     if issue_ids.len() == 1 {
         let issue = client.update_issue(&issue_ids[0], update).await?;
-        print_success(&format!("Issue {} unresolved.", issue.short_id));
+        let message = format!("Issue {} unresolved.", issue.short_id);
+        result.updated(&issue_ids[0], issue).print(&message)
     } else {
         client.update_issues(&issue_ids, update).await?;
-        print_success(&format!("Unresolved {} issues.", issue_ids.len()));
+        let result = result.fetch(client, &issue_ids).await;
+        let message = format!("Unresolved {} issues.", result.count());
+        result.print(&message)
     }
+}
 
-    Ok(())
+pub(super) fn unresolve_update() -> IssueUpdate {
+    IssueUpdate {
+        status: Some(IssueStatus::Unresolved),
+        ..Default::default()
+    }
 }
@@ -0,0 +1,342 @@
+use crate::api::models::{Event, Issue};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::widgets::TableState;
+use std::collections::BTreeSet;
+
+/// Rows moved by PageUp and PageDown
+const PAGE_SIZE: usize = 10;
+
+pub(super) struct IgnorePreset {
+    pub label: &'static str,
+    pub duration: Option<u64>,
+    pub count: Option<u64>,
+    pub until_escalating: bool,
+}
+
+/// Choices of the ignore menu, selected by their number
+pub(super) const IGNORE_PRESETS: &[IgnorePreset] = &[
+    IgnorePreset {
+        label: "Forever",
+        duration: None,
+        count: None,
+        until_escalating: false,
+    },
+    IgnorePreset {
+        label: "For 30 minutes",
+        duration: Some(30),
+        count: None,
+        until_escalating: false,
+    },
+    IgnorePreset {
+        label: "For 1 hour",
+        duration: Some(60),
+        count: None,
+        until_escalating: false,
+    },
+    IgnorePreset {
+        label: "For 24 hours",
+        duration: Some(24 * 60),
+        count: None,
+        until_escalating: false,
+    },
+    IgnorePreset {
+        label: "For 1 week",
+        duration: Some(7 * 24 * 60),
+        count: None,
+        until_escalating: false,
+    },
+    IgnorePreset {
+        label: "Until 100 more events",
+        duration: None,
+        count: Some(100),
+        until_escalating: false,
+    },
+    IgnorePreset {
+        label: "Until escalating",
+        duration: None,
+        count: None,
+        until_escalating: true,
+    },
+];
+
+/// Work that needs the API or the system; everything else is handled by
+/// `App::handle_key` directly
+pub(super) enum Action {
+    Refresh,
+    OpenDetail,
+    ShowEvent(usize),
+    Resolve,
+    Unresolve,
+    Ignore(&'static IgnorePreset),
+    Assign(String),
+    Merge,
+    OpenBrowser,
+}
+
+impl Action {
+    /// Status line shown while the action runs
+    pub fn progress(&self) -> &'static str {
+        match self {
+            Action::Refresh => "Refreshing...",
+            Action::OpenDetail | Action::ShowEvent(_) => "Loading events...",
+            Action::Resolve => "Resolving...",
+            Action::Unresolve => "Unresolving...",
+            Action::Ignore(_) => "Ignoring...",
+            Action::Assign(_) => "Assigning...",
+            Action::Merge => "Merging...",
+            Action::OpenBrowser => "Opening browser...",
+        }
+    }
+}
+
+/// Dialog drawn over the list or detail view
+#[derive(PartialEq, Eq)]
+pub(super) enum Prompt {
+    None,
+    Help,
+    Ignore,
+    Assign,
+    ConfirmMerge,
+}
+
+/// The events of the issue shown in the detail view
+pub(super) struct Detail {
+    pub events: Vec<Event>,
+    pub index: usize,
+    /// Full payload of `events[index]`, which the list endpoint omits
+    pub event: Option<Event>,
+    pub scroll: u16,
+}
+
+pub(super) struct Message {
+    pub text: String,
+    pub error: bool,
+}
+
+pub(super) struct App {
+    pub issues: Vec<Issue>,
+    pub table: TableState,
+    /// IDs of the rows marked for bulk actions and merging
+    pub marked: BTreeSet<String>,
+    pub detail: Option<Detail>,
+    pub prompt: Prompt,
+    /// Assignee typed into the assign prompt
+    pub input: String,
+    pub message: Option<Message>,
+    pub quit: bool,
+}
+
+impl App {
+    pub fn new(issues: Vec<Issue>) -> Self {
+        let mut table = TableState::default();
+        if !issues.is_empty() {
+            table.select(Some(0));
+        }
+        Self {
+            issues,
+            table,
+            marked: BTreeSet::new(),
+            detail: None,
+            prompt: Prompt::None,
+            input: String::new(),
+            message: None,
+            quit: false,
+        }
+    }
+
+    pub fn current(&self) -> Option<&Issue> {
+        self.table.selected().and_then(|i| self.issues.get(i))
+    }
+
+    /// Issues an action applies to: the marked rows in list order, or the
+    /// current row if none are marked or the detail view is open
+    pub fn targets(&self) -> Vec<String> {
+        if self.detail.is_none() && !self.marked.is_empty() {
+            return self
+                .issues
+                .iter()
+                .filter(|issue| self.marked.contains(&issue.id))
+                .map(|issue| issue.id.clone())
+                .collect();
+        }
+        self.current()
+            .map(|issue| vec![issue.id.clone()])
+            .unwrap_or_default()
+    }
+
+    /// Replace the list after a refresh, keeping the cursor in range and
+    /// dropping marks of issues that are gone
+    pub fn set_issues(&mut self, issues: Vec<Issue>) {
+        self.marked
+            .retain(|id| issues.iter().any(|issue| &issue.id == id));
+        let selected = match (self.table.selected(), issues.len()) {
+            (_, 0) => None,
+            (Some(i), len) => Some(i.min(len - 1)),
+            (None, _) => Some(0),
+        };
+        self.issues = issues;
+        self.table.select(selected);
+    }
+
+    /// Swap in an issue returned by an update
+    pub fn replace(&mut self, updated: Issue) {
+        if let Some(issue) = self.issues.iter_mut().find(|i| i.id == updated.id) {
+            *issue = updated;
+        }
+    }
+
+    pub fn info(&mut self, text: impl Into<String>) {
+        self.message = Some(Message {
+            text: text.into(),
+            error: false,
+        });
+    }
+
+    pub fn error(&mut self, text: impl Into<String>) {
+        self.message = Some(Message {
+            text: text.into(),
+            error: true,
+        });
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return None;
+        }
+
+        match self.prompt {
+            Prompt::None => {}
+            Prompt::Help => {
+                self.prompt = Prompt::None;
+                return None;
+            }
+            Prompt::Ignore => return self.ignore_key(key),
+            Prompt::Assign => return self.assign_key(key),
+            Prompt::ConfirmMerge => {
+                self.prompt = Prompt::None;
+                return matches!(key.code, KeyCode::Char('y' | 'Y')).then_some(Action::Merge);
+            }
+        }
+
+        self.message = None;
+        let action = if self.detail.is_some() {
+            self.detail_key(key)
+        } else {
+            self.list_key(key)
+        };
+        if action.is_some() || key.modifiers.contains(KeyModifiers::CONTROL) {
+            return action;
+        }
+
+        let has_issue = self.current().is_some();
+        match key.code {
+            KeyCode::Char('r') if has_issue => Some(Action::Resolve),
+            KeyCode::Char('u') if has_issue => Some(Action::Unresolve),
+            KeyCode::Char('o') if has_issue => Some(Action::OpenBrowser),
+            KeyCode::Char('i') if has_issue => {
+                self.prompt = Prompt::Ignore;
+                None
+            }
+            KeyCode::Char('a') if has_issue => {
+                self.input.clear();
+                self.prompt = Prompt::Assign;
+                None
+            }
+            KeyCode::Char('?') => {
+                self.prompt = Prompt::Help;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn list_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.move_by(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_by(-1),
+            KeyCode::PageDown => self.move_by(PAGE_SIZE as isize),
+            KeyCode::PageUp => self.move_by(-(PAGE_SIZE as isize)),
+            KeyCode::Home | KeyCode::Char('g') => self.move_by(isize::MIN),
+            KeyCode::End | KeyCode::Char('G') => self.move_by(isize::MAX),
+            KeyCode::Char(' ') => {
+                if let Some(id) = self.current().map(|issue| issue.id.clone()) {
+                    if !self.marked.remove(&id) {
+                        self.marked.insert(id);
+                    }
+                    self.move_by(1);
+                }
+            }
+            KeyCode::Enter if self.current().is_some() => return Some(Action::OpenDetail),
+            KeyCode::F(5) => return Some(Action::Refresh),
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Some(Action::Refresh)
+            }
+            KeyCode::Char('m') => {
+                if self.marked.len() < 2 {
+                    self.error("Mark at least two issues with Space to merge them");
+                } else {
+                    self.prompt = Prompt::ConfirmMerge;
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn detail_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let detail = self.detail.as_mut()?;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Backspace => self.detail = None,
+            KeyCode::Down | KeyCode::Char('j') => detail.scroll = detail.scroll.saturating_add(1),
+            KeyCode::Up | KeyCode::Char('k') => detail.scroll = detail.scroll.saturating_sub(1),
+            KeyCode::PageDown => detail.scroll = detail.scroll.saturating_add(PAGE_SIZE as u16),
+            KeyCode::PageUp => detail.scroll = detail.scroll.saturating_sub(PAGE_SIZE as u16),
+            KeyCode::Home | KeyCode::Char('g') => detail.scroll = 0,
+            KeyCode::Right | KeyCode::Char('n' | 'l') if detail.index + 1 < detail.events.len() => {
+                return Some(Action::ShowEvent(detail.index + 1))
+            }
+            KeyCode::Left | KeyCode::Char('p' | 'h') if detail.index > 0 => {
+                return Some(Action::ShowEvent(detail.index - 1))
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn ignore_key(&mut self, key: KeyEvent) -> Option<Action> {
+        self.prompt = Prompt::None;
+        let KeyCode::Char(c) = key.code else {
+            return None;
+        };
+        let index = c.to_digit(10)?.checked_sub(1)? as usize;
+        IGNORE_PRESETS.get(index).map(Action::Ignore)
+    }
+
+    fn assign_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc => self.prompt = Prompt::None,
+            KeyCode::Enter => {
+                self.prompt = Prompt::None;
+                return Some(Action::Assign(self.input.trim().to_string()));
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            _ => {}
+        }
+        None
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        if self.issues.is_empty() {
+            return;
+        }
+        let last = self.issues.len() - 1;
+        let current = self.table.selected().unwrap_or(0);
+        let next = current.saturating_add_signed(delta).min(last);
+        self.table.select(Some(next));
+    }
+}
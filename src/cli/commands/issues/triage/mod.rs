@@ -0,0 +1,178 @@
+mod app;
+mod ui;
+
+use super::assign::assign_update;
+use super::ignore::ignore_update;
+use super::list::ListOptions;
+use super::resolve::{resolve_update, unresolve_update};
+use crate::api::models::{IssueUpdate, ListIssuesParams};
+use crate::api::SentryClient;
+use crate::error::{Result, SentryCliError};
+use app::{Action, App, Detail};
+use ratatui::crossterm::event::{self, Event as TermEvent, KeyEventKind};
+use ratatui::DefaultTerminal;
+use std::io::{self, IsTerminal};
+
+/// Open the full-screen triage view over the issues matching `options`
+pub async fn triage_issues(client: &SentryClient, options: ListOptions) -> Result<()> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(SentryCliError::Validation(
+            "issues triage needs an interactive terminal. Use `sentry issues list` in scripts"
+                .to_string(),
+        ));
+    }
+
+    let params = options.params();
+    let issues = client.list_issues(params.clone()).await?.items;
+
+    let mut terminal = ratatui::try_init()?;
+    let result = run(&mut terminal, client, &params, App::new(issues)).await;
+    ratatui::restore();
+    result
+}
+
+async fn run(
+    terminal: &mut DefaultTerminal,
+    client: &SentryClient,
+    params: &ListIssuesParams,
+    mut app: App,
+) -> Result<()> {
+    while !app.quit {
+        terminal.draw(|frame| ui::draw(frame, &mut app))?;
+
+        let TermEvent::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        if let Some(action) = app.handle_key(key) {
+            app.info(action.progress());
+            terminal.draw(|frame| ui::draw(frame, &mut app))?;
+
+            // API errors are shown in the status line instead of leaving the UI
+            if let Err(e) = perform(client, params, &mut app, action).await {
+                app.error(e.to_string());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn perform(
+    client: &SentryClient,
+    params: &ListIssuesParams,
+    app: &mut App,
+    action: Action,
+) -> Result<()> {
+    match action {
+        Action::Refresh => {
+            let issues = client.list_issues(params.clone()).await?.items;
+            app.set_issues(issues);
+            app.info(format!("Loaded {} issues.", app.issues.len()));
+        }
+        Action::OpenDetail => {
+            let Some(issue) = app.current() else {
+                return Ok(());
+            };
+            let events = client.list_issue_events(&issue.id, None, false).await?;
+            let event = match events.first() {
+                Some(first) => Some(client.get_issue_event(&issue.id, &first.event_id).await?),
+                None => None,
+            };
+            app.detail = Some(Detail {
+                events,
+                index: 0,
+                event,
+                scroll: 0,
+            });
+            app.message = None;
+        }
+        Action::ShowEvent(index) => {
+            let (Some(issue), Some(detail)) = (app.current(), app.detail.as_ref()) else {
+                return Ok(());
+            };
+            let event = client
+                .get_issue_event(&issue.id, &detail.events[index].event_id)
+                .await?;
+            if let Some(detail) = app.detail.as_mut() {
+                detail.index = index;
+                detail.event = Some(event);
+                detail.scroll = 0;
+            }
+            app.message = None;
+        }
+        Action::Resolve => {
+            let update = resolve_update(None, false);
+            update_targets(client, params, app, update, "resolved").await?;
+        }
+        Action::Unresolve => {
+            update_targets(client, params, app, unresolve_update(), "unresolved").await?;
+        }
+        Action::Ignore(preset) => {
+            let update = ignore_update(preset.duration, preset.count, preset.until_escalating);
+            let verb = format!("ignored ({})", preset.label.to_lowercase());
+            update_targets(client, params, app, update, &verb).await?;
+        }
+        Action::Assign(assignee) => {
+            let verb = if assignee.is_empty() {
+                "unassigned".to_string()
+            } else {
+                format!("assigned to {}", assignee)
+            };
+            let update = assign_update(Some(assignee));
+            update_targets(client, params, app, update, &verb).await?;
+        }
+        Action::Merge => {
+            let mut ids = app.targets();
+            // Merge into the row under the cursor if it is marked
+            if let Some(current) = app.current().filter(|i| app.marked.contains(&i.id)) {
+                let primary = ids.iter().position(|id| id == &current.id).unwrap_or(0);
+                ids.swap(0, primary);
+            }
+            let count = ids.len();
+            client.merge_issues(&ids[0], &ids[1..]).await?;
+
+            app.marked.clear();
+            app.set_issues(client.list_issues(params.clone()).await?.items);
+            app.info(format!("Merged {} issues.", count));
+        }
+        Action::OpenBrowser => {
+            if let Some(issue) = app.current() {
+                let permalink = issue.permalink.clone();
+                open::that_detached(&permalink)?;
+                app.info(format!("Opened {}", permalink));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply an update to the targeted issues. A single issue is updated in
+/// place from the response; bulk updates return no issues, so the list is
+/// fetched again.
+async fn update_targets(
+    client: &SentryClient,
+    params: &ListIssuesParams,
+    app: &mut App,
+    update: IssueUpdate,
+    verb: &str,
+) -> Result<()> {
+    let ids = app.targets();
+
+    if let [id] = ids.as_slice() {
+        let issue = client.update_issue(id, update).await?;
+        app.info(format!("Issue {} {}.", issue.short_id, verb));
+        app.replace(issue);
+    } else {
+        client.update_issues(&ids, update).await?;
+        app.marked.clear();
+        app.set_issues(client.list_issues(params.clone()).await?.items);
+        app.info(format!("{} issues {}.", ids.len(), verb));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,392 @@
+use super::app::{App, Detail, Prompt, IGNORE_PRESETS};
+use crate::api::models::{Event, Issue, IssueStatus};
+use crate::output::format_relative_time;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Cell, Clear, Paragraph, Row, Table, Wrap};
+use ratatui::Frame;
+
+/// Breadcrumbs shown at the end of an event
+const BREADCRUMB_LIMIT: usize = 10;
+
+const LIST_KEYS: &str =
+    "↑↓ move  Space mark  Enter details  r resolve  i ignore  a assign  m merge  o open  ? help  q quit";
+const DETAIL_KEYS: &str =
+    "←→ events  ↑↓ scroll  r resolve  u unresolve  i ignore  a assign  o open  Esc back";
+
+const HELP: &[(&str, &str)] = &[
+    ("↑ ↓ / j k", "Move, or scroll the detail view"),
+    ("PgUp PgDn", "Move by a page"),
+    ("g G", "First / last issue"),
+    ("Space", "Mark the issue for bulk actions"),
+    ("Enter", "Show issue details and events"),
+    ("← → / p n", "Previous / next event"),
+    ("r", "Resolve"),
+    ("u", "Unresolve"),
+    ("i", "Ignore, with a choice of presets"),
+    ("a", "Assign to a user, email or team:slug"),
+    ("m", "Merge the marked issues"),
+    ("o", "Open in the browser"),
+    ("F5 / Ctrl+R", "Reload the list"),
+    ("Esc / q", "Back, or quit from the list"),
+];
+
+pub(super) fn draw(frame: &mut Frame, app: &mut App) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let title = match app.current().filter(|_| app.detail.is_some()) {
+        Some(issue) => format!(" Sentry issue {}", issue.short_id),
+        None => format!(
+            " Sentry issues: {} shown, {} marked",
+            app.issues.len(),
+            app.marked.len()
+        ),
+    };
+    frame.render_widget(Line::from(title).bold().reversed(), header);
+
+    match (&app.detail, app.current()) {
+        (Some(detail), Some(issue)) => draw_detail(frame, body, issue, detail),
+        _ => draw_list(frame, body, app),
+    }
+
+    let status = match &app.message {
+        Some(message) if message.error => Line::from(message.text.as_str()).red(),
+        Some(message) => Line::from(message.text.as_str()).green(),
+        None if app.detail.is_some() => Line::from(DETAIL_KEYS).dark_gray(),
+        None => Line::from(LIST_KEYS).dark_gray(),
+    };
+    frame.render_widget(status, footer);
+
+    match app.prompt {
+        Prompt::None => {}
+        Prompt::Help => draw_help(frame),
+        Prompt::Ignore => draw_ignore_menu(frame, app.targets().len()),
+        Prompt::Assign => draw_assign_prompt(frame, &app.input, app.targets().len()),
+        Prompt::ConfirmMerge => draw_confirm_merge(frame, app.marked.len()),
+    }
+}
+
+fn draw_list(frame: &mut Frame, area: Rect, app: &mut App) {
+    if app.issues.is_empty() {
+        let empty = Paragraph::new("No issues found.").block(Block::bordered());
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new([
+        "",
+        "ID",
+        "Status",
+        "Level",
+        "Title",
+        "Events",
+        "Users",
+        "Last Seen",
+        "Assignee",
+    ])
+    .bold();
+
+    let rows = app.issues.iter().map(|issue| {
+        let mark = if app.marked.contains(&issue.id) {
+            "●"
+        } else {
+            ""
+        };
+        let assignee = issue
+            .assigned_to
+            .as_ref()
+            .map(|a| a.name.as_str())
+            .unwrap_or("-");
+        Row::new([
+            Cell::from(mark).cyan(),
+            Cell::from(issue.short_id.as_str()),
+            Cell::from(issue.status.to_string()).style(status_style(issue.status)),
+            Cell::from(issue.level.as_str()).style(level_style(&issue.level)),
+            Cell::from(issue.title.as_str()),
+            Cell::from(issue.count.as_str()),
+            Cell::from(issue.user_count.to_string()),
+            Cell::from(format_relative_time(&issue.last_seen)),
+            Cell::from(assignee),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(1),
+        Constraint::Length(16),
+        Constraint::Length(12),
+        Constraint::Length(7),
+        Constraint::Fill(1),
+        Constraint::Length(7),
+        Constraint::Length(6),
+        Constraint::Length(12),
+        Constraint::Length(16),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::bordered())
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(table, area, &mut app.table);
+}
+
+fn draw_detail(frame: &mut Frame, area: Rect, issue: &Issue, detail: &Detail) {
+    let mut lines = issue_lines(issue);
+    lines.push(Line::default());
+
+    let position = if detail.events.is_empty() {
+        "No events".to_string()
+    } else {
+        format!("Event {} of {}", detail.index + 1, detail.events.len())
+    };
+    lines.push(Line::from(position).bold().underlined());
+    if let Some(event) = &detail.event {
+        lines.extend(event_lines(event));
+    }
+
+    let paragraph = Paragraph::new(Text::from(lines))
+        .block(Block::bordered().title(format!(" {} ", issue.title)))
+        .wrap(Wrap { trim: false })
+        .scroll((detail.scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn issue_lines(issue: &Issue) -> Vec<Line<'_>> {
+    let assignee = issue
+        .assigned_to
+        .as_ref()
+        .map(|a| a.name.as_str())
+        .unwrap_or("Unassigned");
+
+    let mut lines = vec![
+        field("Short ID:", Span::raw(issue.short_id.as_str())),
+        field(
+            "Status:",
+            Span::styled(issue.status.to_string(), status_style(issue.status)),
+        ),
+        field(
+            "Level:",
+            Span::styled(issue.level.as_str(), level_style(&issue.level)),
+        ),
+        field("Project:", Span::raw(issue.project.slug.as_str())),
+        field(
+            "Events:",
+            Span::raw(format!("{} ({} users)", issue.count, issue.user_count)),
+        ),
+        field(
+            "First Seen:",
+            Span::raw(issue.first_seen.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+        ),
+        field(
+            "Last Seen:",
+            Span::raw(issue.last_seen.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+        ),
+        field("Assignee:", Span::raw(assignee)),
+    ];
+    if let Some(culprit) = &issue.culprit {
+        lines.push(field("Culprit:", Span::raw(culprit.as_str())));
+    }
+    lines.push(field("URL:", Span::raw(issue.permalink.as_str()).cyan()));
+    lines
+}
+
+fn event_lines(event: &Event) -> Vec<Line<'_>> {
+    let mut lines = vec![field("Event ID:", Span::raw(event.event_id.as_str()))];
+    if let Some(date) = &event.date_created {
+        lines.push(field(
+            "Date:",
+            Span::raw(date.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+        ));
+    }
+    if let Some(user) = &event.user {
+        let name = user
+            .email
+            .as_deref()
+            .or(user.username.as_deref())
+            .or(user.id.as_deref())
+            .or(user.ip_address.as_deref())
+            .unwrap_or("unknown");
+        lines.push(field("User:", Span::raw(name.to_string())));
+    }
+    if let Some(message) = event.formatted_message() {
+        lines.push(field("Message:", Span::raw(message)));
+    }
+
+    for exception in event.exceptions() {
+        lines.push(Line::default());
+        let heading = format!(
+            "{}: {}",
+            exception.exception_type.as_deref().unwrap_or("Error"),
+            exception.value.as_deref().unwrap_or("")
+        );
+        lines.push(Line::from(heading).red().bold());
+
+        let frames = exception.stacktrace.map(|s| s.frames).unwrap_or_default();
+        // Innermost frame first, where the error was raised
+        for frame in frames.iter().rev() {
+            let location = format!(
+                "  at {} ({}:{})",
+                frame.function.as_deref().unwrap_or("?"),
+                frame
+                    .filename
+                    .as_deref()
+                    .or(frame.module.as_deref())
+                    .unwrap_or("?"),
+                frame.line_no.map(|n| n.to_string()).unwrap_or_default()
+            );
+            if frame.in_app {
+                lines.push(Line::from(location));
+                if let Some(context) = frame.context_line() {
+                    lines.push(Line::from(format!("      {}", context.trim())).yellow());
+                }
+            } else {
+                lines.push(Line::from(location).dark_gray());
+            }
+        }
+    }
+
+    if !event.tags.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from("Tags").bold());
+        for tag in &event.tags {
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {:<24} ", tag.key)).dark_gray(),
+                Span::raw(tag.value.as_str()),
+            ]));
+        }
+    }
+
+    let breadcrumbs = event.breadcrumbs();
+    if !breadcrumbs.is_empty() {
+        let skip = breadcrumbs.len().saturating_sub(BREADCRUMB_LIMIT);
+        lines.push(Line::default());
+        lines.push(Line::from("Breadcrumbs").bold());
+        for crumb in &breadcrumbs[skip..] {
+            let time = crumb
+                .timestamp
+                .map(|t| t.format("%H:%M:%S").to_string())
+                .unwrap_or_else(|| "--:--:--".to_string());
+            let category = crumb
+                .category
+                .as_deref()
+                .or(crumb.breadcrumb_type.as_deref())
+                .unwrap_or("default");
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {} ", time)).dark_gray(),
+                Span::raw(format!(
+                    "{:<16} {}",
+                    category,
+                    crumb.message.as_deref().unwrap_or("")
+                )),
+            ]));
+        }
+    }
+
+    lines
+}
+
+fn field<'a>(label: &'a str, value: Span<'a>) -> Line<'a> {
+    Line::from(vec![Span::raw(format!("{:<12} ", label)).bold(), value])
+}
+
+fn draw_help(frame: &mut Frame) {
+    let lines: Vec<Line> = HELP
+        .iter()
+        .map(|(keys, description)| {
+            Line::from(vec![
+                Span::raw(format!("  {:<14}", keys)).bold(),
+                Span::raw(*description),
+            ])
+        })
+        .collect();
+    draw_popup(frame, " Keys (any key to close) ", lines, 60);
+}
+
+fn draw_ignore_menu(frame: &mut Frame, count: usize) {
+    let mut lines = vec![
+        Line::from(format!("  Ignore {}:", plural(count))),
+        Line::default(),
+    ];
+    lines.extend(
+        IGNORE_PRESETS
+            .iter()
+            .enumerate()
+            .map(|(i, preset)| Line::from(format!("  {}  {}", i + 1, preset.label))),
+    );
+    lines.push(Line::default());
+    lines.push(Line::from("  Esc to cancel").dark_gray());
+    draw_popup(frame, " Ignore ", lines, 40);
+}
+
+fn draw_assign_prompt(frame: &mut Frame, input: &str, count: usize) {
+    let lines = vec![
+        Line::from(format!("  Assign {} to:", plural(count))),
+        Line::default(),
+        Line::from(format!("  > {}_", input)).bold(),
+        Line::default(),
+        Line::from("  Username, email or team:slug. Leave empty to unassign").dark_gray(),
+    ];
+    draw_popup(frame, " Assign ", lines, 60);
+}
+
+fn draw_confirm_merge(frame: &mut Frame, count: usize) {
+    let lines = vec![
+        Line::from(format!("  Merge {} marked issues?", count)),
+        Line::from("  The issue under the cursor is kept if it is marked").dark_gray(),
+        Line::default(),
+        Line::from("  y to merge, any other key to cancel"),
+    ];
+    draw_popup(frame, " Merge ", lines, 60);
+}
+
+fn draw_popup(frame: &mut Frame, title: &str, lines: Vec<Line>, width: u16) {
+    let area = frame.area();
+    let width = width.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(title)),
+        popup,
+    );
+}
+
+fn plural(count: usize) -> String {
+    if count == 1 {
+        "1 issue".to_string()
+    } else {
+        format!("{} issues", count)
+    }
+}
+
+fn status_style(status: IssueStatus) -> Style {
+    let color = match status {
+        IssueStatus::Resolved => Color::Green,
+        IssueStatus::Unresolved => Color::Red,
+        IssueStatus::Ignored => Color::Yellow,
+        IssueStatus::Reprocessing => Color::Cyan,
+    };
+    Style::new().fg(color)
+}
+
+fn level_style(level: &str) -> Style {
+    let color = match level {
+        "fatal" => Color::Magenta,
+        "error" => Color::Red,
+        "warning" => Color::Yellow,
+        "info" => Color::Blue,
+        _ => Color::Reset,
+    };
+    Style::new().fg(color)
+}
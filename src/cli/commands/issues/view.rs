@@ -1,14 +1,14 @@
 use crate::api::SentryClient;
-use crate::cli::args::OutputFormat;
 use crate::error::Result;
-use crate::output::{get_format, print_issue_detail, print_issue_json};
+use crate::output::{is_data_output, print_data, print_issue_detail};
 
 pub async fn view_issue(client: &SentryClient, issue_id: &str) -> Result<()> {
     let issue = client.get_issue(issue_id).await?;
 
-    match get_format() {
-        OutputFormat::Json => print_issue_json(&issue),
-        OutputFormat::Table | OutputFormat::Compact => print_issue_detail(&issue),
+    if is_data_output() {
+        print_data(&issue)?;
+    } else {
+        print_issue_detail(&issue);
     }
 
     Ok(())
@@ -0,0 +1,170 @@
+use super::list::ListOptions;
+use crate::api::models::Issue;
+use crate::api::SentryClient;
+use crate::cli::args::OutputFormat;
+use crate::error::{Result, SentryCliError};
+use crate::output::{get_format, print_issues_watch_table, IssueColumns};
+use chrono::Local;
+use crossterm::cursor::MoveTo;
+use crossterm::execute;
+use crossterm::terminal::{Clear, ClearType};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, IsTerminal, Write};
+use std::time::Duration;
+
+pub struct WatchOptions {
+    pub interval: Duration,
+    pub bell: bool,
+    pub notify: bool,
+}
+
+/// Poll the issue list until interrupted, redrawing the table each time.
+/// Issues that were not in any earlier poll are marked new, and issues whose
+/// event count went up show the increase.
+pub async fn watch_issues(
+    client: &SentryClient,
+    options: ListOptions,
+    watch: WatchOptions,
+    columns: &IssueColumns,
+) -> Result<()> {
+    if !matches!(get_format(), OutputFormat::Table | OutputFormat::Compact) {
+        return Err(SentryCliError::Validation(
+            "--watch only supports table output".to_string(),
+        ));
+    }
+
+    let mut counts: Option<HashMap<String, u64>> = None;
+
+    loop {
+        match options.fetch(client).await {
+            Ok(issues) => {
+                let (new, rising) = match &counts {
+                    Some(previous) => changes(previous, &issues),
+                    None => Default::default(),
+                };
+
+                redraw()?;
+                println!(
+                    "Every {}: sentry issues list (updated {}, Ctrl+C to stop)",
+                    format_interval(watch.interval),
+                    Local::now().format("%H:%M:%S")
+                );
+                println!();
+                print_issues_watch_table(&issues, &new, &rising, columns);
+
+                if !new.is_empty() || !rising.is_empty() {
+                    alert(&watch, new.len(), rising.len());
+                }
+
+                let counts = counts.get_or_insert_with(HashMap::new);
+                for issue in &issues {
+                    counts.insert(issue.id.clone(), event_count(issue));
+                }
+            }
+            Err(e) if is_transient(&e) => {
+                // Keep the last table on screen and try again next interval
+                eprintln!("Poll failed at {}: {}", Local::now().format("%H:%M:%S"), e);
+            }
+            Err(e) => return Err(e),
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(watch.interval) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+/// Issues not seen before, and the event count increase of known issues
+fn changes(
+    previous: &HashMap<String, u64>,
+    issues: &[Issue],
+) -> (HashSet<String>, HashMap<String, u64>) {
+    let mut new = HashSet::new();
+    let mut rising = HashMap::new();
+
+    for issue in issues {
+        match previous.get(&issue.id) {
+            None => {
+                new.insert(issue.id.clone());
+            }
+            Some(&before) => {
+                let now = event_count(issue);
+                if now > before {
+                    rising.insert(issue.id.clone(), now - before);
+                }
+            }
+        }
+    }
+
+    (new, rising)
+}
+
+fn event_count(issue: &Issue) -> u64 {
+    issue.count.parse().unwrap_or(0)
+}
+
+fn is_transient(error: &SentryCliError) -> bool {
+    match error {
+        SentryCliError::Network(_) | SentryCliError::RateLimited { .. } => true,
+        SentryCliError::Api { status, .. } => *status >= 500,
+        _ => false,
+    }
+}
+
+/// Clear the terminal so the table is redrawn in place. When the output is
+/// not a terminal, polls are separated by a blank line instead.
+fn redraw() -> Result<()> {
+    let mut stdout = io::stdout();
+    if stdout.is_terminal() {
+        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
+    } else {
+        println!();
+    }
+    Ok(())
+}
+
+fn alert(watch: &WatchOptions, new: usize, rising: usize) {
+    if watch.bell {
+        print!("\x07");
+        let _ = io::stdout().flush();
+    }
+
+    if watch.notify {
+        let mut parts = Vec::new();
+        if new > 0 {
+            parts.push(format!("{} new issue(s)", new));
+        }
+        if rising > 0 {
+            parts.push(format!("{} issue(s) with new events", rising));
+        }
+
+        // D-Bus clients may block on their own executor, so keep them off
+        // the runtime thread
+        let result = std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    notify_rust::Notification::new()
+                        .summary("Sentry")
+                        .body(&parts.join(", "))
+                        .show()
+                        .map(|_| ())
+                })
+                .join()
+        });
+        if let Ok(Err(e)) = result {
+            eprintln!("Could not show a desktop notification: {}", e);
+        }
+    }
+}
+
+fn format_interval(interval: Duration) -> String {
+    let seconds = interval.as_secs();
+    if seconds.is_multiple_of(3600) {
+        format!("{}h", seconds / 3600)
+    } else if seconds.is_multiple_of(60) {
+        format!("{}m", seconds / 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
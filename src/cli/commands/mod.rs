@@ -1,2 +1,26 @@
+pub mod auth;
 pub mod config;
+pub mod debug_files;
+pub mod events;
 pub mod issues;
+pub mod monitors;
+pub mod orgs;
+pub mod projects;
+pub mod releases;
+pub mod send;
+pub mod sourcemaps;
+pub mod teams;
+
+use crate::error::Result;
+use std::io::{self, Write};
+
+/// Ask a yes/no question on stderr, so that it stays out of piped output.
+/// Anything but `y` is no.
+pub fn prompt_confirmation(question: &str) -> Result<bool> {
+    eprint!("{} [y/N]: ", question);
+    io::stderr().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().eq_ignore_ascii_case("y"))
+}
@@ -0,0 +1,254 @@
+use crate::api::models::{
+    CheckInStatus, CheckInUpdate, MonitorConfigUpsert, MonitorSchedule, NewCheckIn,
+};
+use crate::api::SentryClient;
+use crate::cli::args::{CheckInStatusArg, MonitorConfigArgs};
+use crate::error::{Result, SentryCliError};
+use crate::output::{
+    is_data_output, print_checkins_table, print_data, print_monitor_detail, print_monitors_table,
+    print_success,
+};
+use serde_json::json;
+use std::time::Instant;
+use tokio::process::Command;
+
+const INTERVAL_UNITS: &[&str] = &["minute", "hour", "day", "week", "month", "year"];
+
+pub struct RunOptions {
+    pub environment: Option<String>,
+    pub config: MonitorConfigArgs,
+    pub command: Vec<String>,
+}
+
+pub struct CheckinOptions {
+    pub status: CheckInStatusArg,
+    pub id: Option<String>,
+    pub duration: Option<u64>,
+    pub environment: Option<String>,
+    pub config: MonitorConfigArgs,
+}
+
+pub async fn list_monitors(client: &SentryClient) -> Result<()> {
+    let monitors = client.list_monitors().await?;
+
+    if is_data_output() {
+        print_data(&monitors)?;
+    } else {
+        print_monitors_table(&monitors);
+    }
+
+    Ok(())
+}
+
+pub async fn view_monitor(client: &SentryClient, slug: &str, limit: u32) -> Result<()> {
+    let monitor = client.get_monitor(slug).await?;
+    let checkins = client.list_checkins(slug, limit).await?;
+
+    if is_data_output() {
+        print_data(&json!({
+            "monitor": monitor,
+            "checkIns": checkins,
+        }))?;
+    } else {
+        print_monitor_detail(&monitor);
+        print_checkins_table(&checkins);
+    }
+
+    Ok(())
+}
+
+/// Run a command wrapped in check-ins and return its exit code.
+///
+/// Failing to reach Sentry never prevents the job from running; check-in
+/// errors are reported as warnings.
+pub async fn run(client: &SentryClient, slug: &str, options: RunOptions) -> Result<i32> {
+    let monitor_config = parse_config(&options.config)?;
+
+    let started = client
+        .create_checkin(
+            slug,
+            &NewCheckIn {
+                status: CheckInStatus::InProgress,
+                duration: None,
+                environment: options.environment.clone(),
+                monitor_config,
+            },
+        )
+        .await;
+    let checkin_id = match started {
+        Ok(checkin) => Some(checkin.id),
+        Err(e) => {
+            eprintln!("Warning: failed to send in_progress check-in: {}", e);
+            None
+        }
+    };
+
+    let (program, args) = options
+        .command
+        .split_first()
+        .ok_or_else(|| SentryCliError::Validation("No command given".to_string()))?;
+
+    let start = Instant::now();
+    let result = Command::new(program).args(args).status().await;
+    let duration = start.elapsed().as_millis() as u64;
+
+    let exit_code = match &result {
+        Ok(status) => status.code().unwrap_or(1),
+        Err(_) => 127,
+    };
+    let status = if exit_code == 0 {
+        CheckInStatus::Ok
+    } else {
+        CheckInStatus::Error
+    };
+
+    let finished = match &checkin_id {
+        Some(id) => {
+            let update = CheckInUpdate {
+                status,
+                duration: Some(duration),
+            };
+            client.update_checkin(slug, id, &update).await
+        }
+        None => {
+            let checkin = NewCheckIn {
+                status,
+                duration: Some(duration),
+                environment: options.environment,
+                monitor_config: None,
+            };
+            client.create_checkin(slug, &checkin).await.map(|_| ())
+        }
+    };
+    if let Err(e) = &finished {
+        eprintln!("Warning: failed to send {} check-in: {}", status, e);
+    }
+    let sent = finished.is_ok();
+    let report = if sent {
+        format!("sent {} check-in to {}", status, slug)
+    } else {
+        format!("{} check-in to {} failed", status, slug)
+    };
+
+    let result = result
+        .map_err(|e| SentryCliError::Validation(format!("Failed to run {}: {}", program, e)))?;
+
+    if is_data_output() {
+        print_data(&json!({
+            "monitor": slug,
+            "checkInId": checkin_id,
+            "status": status,
+            "duration": duration,
+            "exitCode": exit_code,
+            "checkInSent": sent,
+        }))?;
+    } else if result.success() {
+        print_success(&format!(
+            "{} finished in {:.1}s, {}.",
+            program,
+            duration as f64 / 1000.0,
+            report
+        ));
+    } else {
+        eprintln!(
+            "{} exited with code {} after {:.1}s, {}.",
+            program,
+            exit_code,
+            duration as f64 / 1000.0,
+            report
+        );
+    }
+
+    Ok(exit_code)
+}
+
+pub async fn checkin(client: &SentryClient, slug: &str, options: CheckinOptions) -> Result<()> {
+    let status = match options.status {
+        CheckInStatusArg::InProgress => CheckInStatus::InProgress,
+        CheckInStatusArg::Ok => CheckInStatus::Ok,
+        CheckInStatusArg::Error => CheckInStatus::Error,
+    };
+
+    let id = match options.id {
+        Some(id) => {
+            if options.config.schedule.is_some() {
+                return Err(SentryCliError::Validation(
+                    "--schedule cannot be used when updating an existing check-in".to_string(),
+                ));
+            }
+            let update = CheckInUpdate {
+                status,
+                duration: options.duration,
+            };
+            client.update_checkin(slug, &id, &update).await?;
+            id
+        }
+        None => {
+            let checkin = NewCheckIn {
+                status,
+                duration: options.duration,
+                environment: options.environment,
+                monitor_config: parse_config(&options.config)?,
+            };
+            client.create_checkin(slug, &checkin).await?.id
+        }
+    };
+
+    if is_data_output() {
+        print_data(&json!({
+            "monitor": slug,
+            "checkInId": id,
+            "status": status,
+        }))?;
+    } else {
+        print_success(&format!("Sent {} check-in {} to {}.", status, id, slug));
+    }
+
+    Ok(())
+}
+
+fn parse_config(args: &MonitorConfigArgs) -> Result<Option<MonitorConfigUpsert>> {
+    let Some(schedule) = &args.schedule else {
+        return Ok(None);
+    };
+
+    Ok(Some(MonitorConfigUpsert {
+        schedule: parse_schedule(schedule)?,
+        checkin_margin: args.checkin_margin,
+        max_runtime: args.max_runtime,
+        timezone: args.timezone.clone(),
+    }))
+}
+
+/// Parse `"<n> <unit>"` as an interval schedule, anything else as crontab
+fn parse_schedule(schedule: &str) -> Result<MonitorSchedule> {
+    let parts: Vec<&str> = schedule.split_whitespace().collect();
+
+    if let [value, unit] = parts.as_slice() {
+        if let Ok(value) = value.parse::<u32>() {
+            let unit = unit.trim_end_matches('s');
+            if !INTERVAL_UNITS.contains(&unit) {
+                return Err(SentryCliError::Validation(format!(
+                    "Invalid interval unit: {}. Expected one of: {}",
+                    unit,
+                    INTERVAL_UNITS.join(", ")
+                )));
+            }
+            return Ok(MonitorSchedule::Interval {
+                value,
+                unit: unit.to_string(),
+            });
+        }
+    }
+
+    if parts.len() == 5 || (parts.len() == 1 && parts[0].starts_with('@')) {
+        return Ok(MonitorSchedule::Crontab {
+            value: parts.join(" "),
+        });
+    }
+
+    Err(SentryCliError::Validation(format!(
+        "Invalid schedule: {}. Expected a crontab expression like '0 3 * * *' or an interval like '10 minutes'",
+        schedule
+    )))
+}
@@ -0,0 +1,31 @@
+use crate::api::SentryClient;
+use crate::error::Result;
+use crate::output::{is_data_output, print_data, print_org_detail, print_orgs_table};
+
+pub async fn list_orgs(client: &SentryClient) -> Result<()> {
+    let orgs = client.list_organizations().await?;
+
+    if is_data_output() {
+        print_data(&orgs)?;
+    } else {
+        print_orgs_table(&orgs);
+    }
+
+    Ok(())
+}
+
+pub async fn view_org(client: &SentryClient, slug: Option<&str>) -> Result<()> {
+    let slug = match slug {
+        Some(slug) => slug,
+        None => client.org()?,
+    };
+    let org = client.get_organization(slug).await?;
+
+    if is_data_output() {
+        print_data(&org)?;
+    } else {
+        print_org_detail(&org);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use crate::api::models::{NewProject, NewProjectKey};
+use crate::api::SentryClient;
+use crate::cli::commands::prompt_confirmation;
+use crate::error::Result;
+use crate::output::{
+    is_data_output, print_data, print_message, print_project_detail, print_project_keys_table,
+    print_projects_table, print_success,
+};
+use serde::Serialize;
+use serde_json::json;
+
+pub struct CreateOptions {
+    pub team: String,
+    pub slug: Option<String>,
+    pub platform: Option<String>,
+}
+
+pub async fn list_projects(client: &SentryClient) -> Result<()> {
+    let projects = client.list_projects().await?;
+
+    if is_data_output() {
+        print_data(&projects)?;
+    } else {
+        print_projects_table(&projects);
+    }
+
+    Ok(())
+}
+
+pub async fn view_project(client: &SentryClient, slug: &str) -> Result<()> {
+    let project = client.get_project(slug).await?;
+    let keys = client.list_project_keys(slug).await?;
+
+    if is_data_output() {
+        print_data(&json!({
+            "project": project,
+            "keys": keys,
+        }))?;
+    } else {
+        print_project_detail(&project);
+        print_project_keys_table(&keys);
+    }
+
+    Ok(())
+}
+
+pub async fn create_project(
+    client: &SentryClient,
+    name: &str,
+    options: CreateOptions,
+) -> Result<()> {
+    let project = NewProject {
+        name: name.to_string(),
+        slug: options.slug,
+        platform: options.platform,
+    };
+
+    let project = client.create_project(&options.team, &project).await?;
+
+    // Sentry creates a default client key along with the project
+    let dsn = client
+        .list_project_keys(&project.slug)
+        .await?
+        .into_iter()
+        .find(|key| key.is_active)
+        .map(|key| key.dsn.public);
+
+    if is_data_output() {
+        print_data(&json!({
+            "project": project,
+            "dsn": dsn,
+        }))?;
+    } else {
+        print_success(&format!("Created project {}.", project.slug));
+        match dsn {
+            Some(dsn) => println!("DSN: {}", dsn),
+            None => print_message(&format!(
+                "No client key found. Create one with 'sentry projects keys create {}'.",
+                project.slug
+            ))?,
+        }
+    }
+
+    Ok(())
+}
+
+/// What `projects delete` did, printed in the data formats
+#[derive(Serialize)]
+struct ProjectDeletion<'a> {
+    project: &'a str,
+    /// Whether the project was scheduled for deletion, which happens later
+    deleted: bool,
+    /// Whether the deletion was declined at the confirmation prompt
+    cancelled: bool,
+}
+
+pub async fn delete_project(client: &SentryClient, slug: &str, confirm: bool) -> Result<()> {
+    if !confirm {
+        let question = format!(
+            "Are you sure you want to delete project {} and all of its data?",
+            slug
+        );
+        if !prompt_confirmation(&question)? {
+            if is_data_output() {
+                return print_data(&ProjectDeletion {
+                    project: slug,
+                    deleted: false,
+                    cancelled: true,
+                });
+            }
+            return print_message("Cancelled.");
+        }
+    }
+
+    client.delete_project(slug).await?;
+
+    if is_data_output() {
+        print_data(&ProjectDeletion {
+            project: slug,
+            deleted: true,
+            cancelled: false,
+        })?;
+    } else {
+        print_success(&format!("Project {} scheduled for deletion.", slug));
+    }
+
+    Ok(())
+}
+
+pub async fn list_keys(client: &SentryClient, project: &str) -> Result<()> {
+    let keys = client.list_project_keys(project).await?;
+
+    if is_data_output() {
+        print_data(&keys)?;
+    } else {
+        print_project_keys_table(&keys);
+    }
+
+    Ok(())
+}
+
+pub async fn create_key(client: &SentryClient, project: &str, name: Option<String>) -> Result<()> {
+    let key = client
+        .create_project_key(project, &NewProjectKey { name })
+        .await?;
+
+    if is_data_output() {
+        print_data(&key)?;
+    } else {
+        print_success(&format!("Created key {} for {}.", key.name, project));
+        println!("DSN: {}", key.dsn.public);
+    }
+
+    Ok(())
+}
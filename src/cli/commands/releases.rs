@@ -0,0 +1,325 @@
+use crate::api::models::{
+    CommitSpec, ListReleasesParams, NewDeploy, NewRelease, RefSpec, ReleaseUpdate,
+};
+use crate::api::SentryClient;
+use crate::cli::commands::prompt_confirmation;
+use crate::error::{Result, SentryCliError};
+use crate::git;
+use crate::output::{
+    is_data_output, print_data, print_deploys_table, print_message, print_release_detail,
+    print_releases_table, print_success,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+pub struct NewOptions {
+    pub projects: Vec<String>,
+    pub url: Option<String>,
+    pub git_ref: Option<String>,
+    pub finalize: bool,
+}
+
+pub struct NewDeployOptions {
+    pub environment: String,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub started: Option<String>,
+    pub finished: Option<String>,
+    pub projects: Vec<String>,
+}
+
+pub enum CommitsMode {
+    /// Read commits from the local repository since the previous release
+    Auto {
+        remote: String,
+        initial_depth: usize,
+    },
+    /// Explicit `REPO@REV` or `REPO@PREV..REV` ranges
+    Refs(Vec<String>),
+    Clear,
+}
+
+pub async fn new_release(client: &SentryClient, version: &str, options: NewOptions) -> Result<()> {
+    let release = NewRelease {
+        version: version.to_string(),
+        projects: options.projects,
+        git_ref: options.git_ref,
+        url: options.url,
+        date_released: options.finalize.then(Utc::now),
+    };
+
+    let release = client.create_release(&release).await?;
+
+    if is_data_output() {
+        print_data(&release)?;
+    } else {
+        if options.finalize {
+            print_success(&format!(
+                "Created and finalized release {}.",
+                release.version
+            ));
+        } else {
+            print_success(&format!("Created release {}.", release.version));
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn finalize_release(
+    client: &SentryClient,
+    version: &str,
+    released: Option<&str>,
+) -> Result<()> {
+    let date_released = match released {
+        Some(date) => parse_date("--released", date)?,
+        None => Utc::now(),
+    };
+
+    let update = ReleaseUpdate {
+        date_released: Some(date_released),
+        ..Default::default()
+    };
+
+    let release = client.update_release(version, &update).await?;
+
+    if is_data_output() {
+        print_data(&release)?;
+    } else {
+        print_success(&format!("Finalized release {}.", release.version));
+    }
+
+    Ok(())
+}
+
+pub async fn list_releases(
+    client: &SentryClient,
+    project: Option<String>,
+    query: Option<String>,
+    limit: u32,
+) -> Result<()> {
+    let params = ListReleasesParams {
+        project,
+        query,
+        limit: Some(limit),
+    };
+
+    let releases = client.list_releases(&params).await?;
+
+    if is_data_output() {
+        print_data(&releases)?;
+    } else {
+        print_releases_table(&releases);
+    }
+
+    Ok(())
+}
+
+pub async fn view_release(client: &SentryClient, version: &str) -> Result<()> {
+    let release = client.get_release(version).await?;
+
+    if is_data_output() {
+        print_data(&release)?;
+    } else {
+        print_release_detail(&release);
+    }
+
+    Ok(())
+}
+
+pub async fn set_commits(client: &SentryClient, version: &str, mode: CommitsMode) -> Result<()> {
+    let update = match mode {
+        CommitsMode::Auto {
+            remote,
+            initial_depth,
+        } => {
+            let repository = git::repository_name(&remote)?;
+            let head = git::head_commit()?;
+            let previous = client
+                .get_previous_release(version)
+                .await?
+                .last_commit
+                .map(|c| c.id);
+
+            let commits = git::commits_between(previous.as_deref(), &head, initial_depth)?;
+            if commits.is_empty() {
+                return Err(SentryCliError::Git(format!(
+                    "No new commits found since {}",
+                    previous.as_deref().unwrap_or("the initial commit")
+                )));
+            }
+
+            let commits = commits
+                .into_iter()
+                .map(|c| CommitSpec {
+                    id: c.id,
+                    repository: repository.clone(),
+                    message: Some(c.message),
+                    author_name: Some(c.author_name),
+                    author_email: Some(c.author_email),
+                    timestamp: c.timestamp,
+                })
+                .collect();
+
+            ReleaseUpdate {
+                commits: Some(commits),
+                ..Default::default()
+            }
+        }
+        CommitsMode::Refs(specs) => {
+            if specs.is_empty() {
+                return Err(SentryCliError::Validation(
+                    "Must specify --auto, --commit <REPO@REV> or --clear".to_string(),
+                ));
+            }
+            let refs = specs
+                .iter()
+                .map(|spec| parse_ref_spec(spec))
+                .collect::<Result<Vec<_>>>()?;
+
+            ReleaseUpdate {
+                refs: Some(refs),
+                ..Default::default()
+            }
+        }
+        CommitsMode::Clear => ReleaseUpdate {
+            commits: Some(Vec::new()),
+            ..Default::default()
+        },
+    };
+
+    let release = client.update_release(version, &update).await?;
+
+    if is_data_output() {
+        print_data(&release)?;
+    } else {
+        print_success(&format!(
+            "Release {} now has {} commit(s).",
+            release.version, release.commit_count
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parse `REPO@REV` or `REPO@PREV..REV`
+fn parse_ref_spec(spec: &str) -> Result<RefSpec> {
+    let invalid = || {
+        SentryCliError::Validation(format!(
+            "Invalid commit spec: {}. Expected REPO@REV or REPO@PREV..REV",
+            spec
+        ))
+    };
+
+    let (repository, range) = spec.rsplit_once('@').ok_or_else(invalid)?;
+    let (previous_commit, commit) = match range.split_once("..") {
+        Some((prev, rev)) => (Some(prev.to_string()), rev),
+        None => (None, range),
+    };
+
+    if repository.is_empty() || commit.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(RefSpec {
+        repository: repository.to_string(),
+        commit: commit.to_string(),
+        previous_commit,
+    })
+}
+
+/// What `releases delete` did, printed in the data formats
+#[derive(Serialize)]
+struct ReleaseDeletion<'a> {
+    version: &'a str,
+    deleted: bool,
+    /// Whether the deletion was declined at the confirmation prompt
+    cancelled: bool,
+}
+
+pub async fn delete_release(client: &SentryClient, version: &str, confirm: bool) -> Result<()> {
+    if !confirm {
+        let question = format!("Are you sure you want to delete release {}?", version);
+        if !prompt_confirmation(&question)? {
+            if is_data_output() {
+                return print_data(&ReleaseDeletion {
+                    version,
+                    deleted: false,
+                    cancelled: true,
+                });
+            }
+            return print_message("Cancelled.");
+        }
+    }
+
+    client.delete_release(version).await?;
+
+    if is_data_output() {
+        print_data(&ReleaseDeletion {
+            version,
+            deleted: true,
+            cancelled: false,
+        })?;
+    } else {
+        print_success(&format!("Release {} deleted.", version));
+    }
+
+    Ok(())
+}
+
+pub async fn new_deploy(
+    client: &SentryClient,
+    version: &str,
+    options: NewDeployOptions,
+) -> Result<()> {
+    let date_started = options
+        .started
+        .as_deref()
+        .map(|d| parse_date("--started", d))
+        .transpose()?;
+    let date_finished = options
+        .finished
+        .as_deref()
+        .map(|d| parse_date("--finished", d))
+        .transpose()?;
+
+    let deploy = NewDeploy {
+        environment: options.environment,
+        name: options.name,
+        url: options.url,
+        date_started,
+        date_finished,
+        projects: options.projects,
+    };
+
+    let deploy = client.create_deploy(version, &deploy).await?;
+
+    if is_data_output() {
+        print_data(&deploy)?;
+    } else {
+        print_success(&format!(
+            "Recorded deploy of {} to {}.",
+            version, deploy.environment
+        ));
+    }
+
+    Ok(())
+}
+
+pub async fn list_deploys(client: &SentryClient, version: &str) -> Result<()> {
+    let deploys = client.list_deploys(version).await?;
+
+    if is_data_output() {
+        print_data(&deploys)?;
+    } else {
+        print_deploys_table(&deploys);
+    }
+
+    Ok(())
+}
+
+fn parse_date(flag: &str, value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|e| SentryCliError::Validation(format!("Invalid {} date: {}", flag, e)))
+}
@@ -0,0 +1,105 @@
+use crate::api::envelope::Envelope;
+use crate::api::IngestClient;
+use crate::cli::args::{EventLevel, SendEventArgs};
+use crate::error::{Result, SentryCliError};
+use crate::output::{is_data_output, print_data, print_success};
+use chrono::Utc;
+use serde_json::{json, Map, Value};
+use std::path::Path;
+use uuid::Uuid;
+
+pub async fn send_event(client: &IngestClient, args: SendEventArgs) -> Result<()> {
+    let event_id = Uuid::new_v4().simple().to_string();
+
+    let level = match args.level {
+        EventLevel::Debug => "debug",
+        EventLevel::Info => "info",
+        EventLevel::Warning => "warning",
+        EventLevel::Error => "error",
+        EventLevel::Fatal => "fatal",
+    };
+
+    let mut event = json!({
+        "event_id": event_id,
+        "timestamp": Utc::now().to_rfc3339(),
+        "platform": "other",
+        "level": level,
+        "logentry": { "formatted": args.message.join("\n") },
+        "tags": parse_pairs("tag", &args.tag)?,
+        "extra": parse_pairs("extra", &args.extra)?,
+        "sdk": {
+            "name": "sentry-cli",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+    });
+    if let Some(release) = args.release {
+        event["release"] = json!(release);
+    }
+    if let Some(environment) = args.environment {
+        event["environment"] = json!(environment);
+    }
+    if !args.fingerprint.is_empty() {
+        event["fingerprint"] = json!(args.fingerprint);
+    }
+
+    let envelope = Envelope::from_event(&event_id, &event)?;
+    let id = client.send_envelope(&envelope).await?.unwrap_or(event_id);
+
+    if is_data_output() {
+        print_data(&json!({ "eventId": id }))?;
+    } else {
+        print_success(&format!("Event sent: {}", id));
+    }
+
+    Ok(())
+}
+
+pub async fn send_envelope(client: &IngestClient, path: &Path) -> Result<()> {
+    let data = std::fs::read(path)?;
+    let envelope = Envelope::parse(&data)?;
+
+    if envelope.items().is_empty() {
+        return Err(SentryCliError::Validation(format!(
+            "Envelope {} contains no items",
+            path.display()
+        )));
+    }
+
+    let id = client.send_envelope(&envelope).await?;
+    let item_types: Vec<&str> = envelope.items().iter().map(|i| i.item_type()).collect();
+
+    if is_data_output() {
+        print_data(&json!({
+            "eventId": id,
+            "items": item_types,
+        }))?;
+    } else {
+        let summary = format!(
+            "Envelope sent with {} item(s): {}",
+            item_types.len(),
+            item_types.join(", ")
+        );
+        match id {
+            Some(id) => print_success(&format!("{} (event {})", summary, id)),
+            None => print_success(&summary),
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse repeated `KEY:VALUE` arguments
+fn parse_pairs(kind: &str, pairs: &[String]) -> Result<Map<String, Value>> {
+    pairs
+        .iter()
+        .map(|pair| match pair.split_once(':') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), json!(value.trim())))
+            }
+            _ => Err(SentryCliError::Validation(format!(
+                "Invalid {} '{}'. Expected KEY:VALUE",
+                kind, pair
+            ))),
+        })
+        .collect()
+}
@@ -0,0 +1,181 @@
+use crate::api::models::{AssembleBundleRequest, ChunkedFileState};
+use crate::api::SentryClient;
+use crate::error::{Result, SentryCliError};
+use crate::output::{is_data_output, print_data, print_message, print_success};
+use crate::upload::sourcemaps::{self, BundleOptions, InjectOutcome};
+use crate::upload::{upload_missing_chunks, ChunkedFile};
+use serde_json::json;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+pub struct UploadOptions {
+    pub projects: Vec<String>,
+    pub release: Option<String>,
+    pub dist: Option<String>,
+    pub url_prefix: String,
+    pub wait: bool,
+    /// How long to wait for processing before giving up
+    pub wait_timeout: Duration,
+}
+
+pub fn inject(paths: &[PathBuf], dry_run: bool) -> Result<()> {
+    let files = sourcemaps::collect_files(paths, false)?;
+    let mut results = Vec::new();
+    let mut injected = 0;
+
+    for file in &files {
+        match sourcemaps::inject(&file.path, dry_run)? {
+            InjectOutcome::Injected {
+                debug_id,
+                sourcemap,
+            } => {
+                injected += 1;
+                let map = sourcemap.as_ref().map(|p| p.display().to_string());
+                if !is_data_output() {
+                    match &map {
+                        Some(map) => print_message(&format!(
+                            "{} {} (+ {})",
+                            debug_id,
+                            file.path.display(),
+                            map
+                        ))?,
+                        None => print_message(&format!(
+                            "{} {} (no source map found)",
+                            debug_id,
+                            file.path.display()
+                        ))?,
+                    }
+                }
+                results.push(json!({
+                    "path": file.path,
+                    "debugId": debug_id.to_string(),
+                    "sourcemap": map,
+                    "injected": true,
+                }));
+            }
+            InjectOutcome::AlreadyInjected { debug_id } => {
+                results.push(json!({
+                    "path": file.path,
+                    "debugId": debug_id,
+                    "injected": false,
+                }));
+            }
+        }
+    }
+
+    if is_data_output() {
+        print_data(&results)?;
+    } else if dry_run {
+        print_success(&format!(
+            "Would inject debug IDs into {} of {} file(s) (dry run).",
+            injected,
+            files.len()
+        ));
+    } else {
+        print_success(&format!(
+            "Injected debug IDs into {} of {} file(s).",
+            injected,
+            files.len()
+        ));
+    }
+
+    Ok(())
+}
+
+pub async fn upload(
+    client: &SentryClient,
+    paths: &[PathBuf],
+    options: UploadOptions,
+) -> Result<()> {
+    let files = sourcemaps::collect_files(paths, true)?;
+    if files.is_empty() {
+        return Err(SentryCliError::Validation(
+            "No JavaScript files or source maps found".to_string(),
+        ));
+    }
+
+    let bundle = sourcemaps::build_bundle(
+        &files,
+        &BundleOptions {
+            org: client.org()?,
+            projects: &options.projects,
+            release: options.release.as_deref(),
+            dist: options.dist.as_deref(),
+            url_prefix: &options.url_prefix,
+        },
+    )?;
+
+    if bundle.debug_ids.is_empty() && options.release.is_none() {
+        return Err(SentryCliError::Validation(
+            "No debug IDs found. Run 'sentry sourcemaps inject' first or pass --release"
+                .to_string(),
+        ));
+    }
+
+    let chunk_options = client.get_chunk_upload_options().await?;
+    if !chunk_options.accepts("artifact_bundles") {
+        return Err(SentryCliError::Validation(
+            "This Sentry server does not support artifact bundle uploads".to_string(),
+        ));
+    }
+
+    let chunked = ChunkedFile::new(bundle.data, &chunk_options)?;
+    let request = AssembleBundleRequest {
+        checksum: chunked.checksum.clone(),
+        chunks: chunked.chunk_checksums.clone(),
+        projects: options.projects,
+        version: options.release,
+        dist: options.dist,
+    };
+
+    let mut response = client.assemble_artifact_bundle(&request).await?;
+    if !response.missing_chunks.is_empty() {
+        upload_missing_chunks(
+            client,
+            &chunk_options,
+            &[&chunked],
+            &response.missing_chunks,
+        )
+        .await?;
+        response = client.assemble_artifact_bundle(&request).await?;
+    }
+
+    let started = Instant::now();
+    while options.wait && response.state.is_pending() {
+        if started.elapsed() >= options.wait_timeout {
+            return Err(SentryCliError::Processing(format!(
+                "artifact bundle still processing after {}s",
+                options.wait_timeout.as_secs()
+            )));
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        response = client.assemble_artifact_bundle(&request).await?;
+    }
+
+    if response.state == ChunkedFileState::Error {
+        return Err(SentryCliError::Processing(
+            response
+                .detail
+                .unwrap_or_else(|| "Artifact bundle assembly failed".to_string()),
+        ));
+    }
+
+    if is_data_output() {
+        print_data(&json!({
+            "checksum": chunked.checksum,
+            "size": chunked.len(),
+            "files": bundle.file_count,
+            "debugIds": bundle.debug_ids,
+            "state": response.state,
+        }))?;
+    } else {
+        print_success(&format!(
+            "Uploaded artifact bundle {} ({} file(s), {} debug ID(s)).",
+            chunked.checksum,
+            bundle.file_count,
+            bundle.debug_ids.len()
+        ));
+    }
+
+    Ok(())
+}
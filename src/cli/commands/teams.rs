@@ -0,0 +1,47 @@
+use crate::api::models::NewTeam;
+use crate::api::SentryClient;
+use crate::error::Result;
+use crate::output::{
+    is_data_output, print_data, print_success, print_team_members_table, print_teams_table,
+};
+
+pub async fn list_teams(client: &SentryClient) -> Result<()> {
+    let teams = client.list_teams().await?;
+
+    if is_data_output() {
+        print_data(&teams)?;
+    } else {
+        print_teams_table(&teams);
+    }
+
+    Ok(())
+}
+
+pub async fn create_team(client: &SentryClient, slug: &str, name: Option<String>) -> Result<()> {
+    let team = NewTeam {
+        slug: slug.to_string(),
+        name,
+    };
+
+    let team = client.create_team(&team).await?;
+
+    if is_data_output() {
+        print_data(&team)?;
+    } else {
+        print_success(&format!("Created team {}.", team.slug));
+    }
+
+    Ok(())
+}
+
+pub async fn list_members(client: &SentryClient, team: &str) -> Result<()> {
+    let members = client.list_team_members(team).await?;
+
+    if is_data_output() {
+        print_data(&members)?;
+    } else {
+        print_team_members_table(&members);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use super::secrets::TokenStorage;
+use super::{Config, Settings};
+use crate::api::dsn::Dsn;
+use crate::api::retry::DEFAULT_MAX_RETRIES;
+use crate::error::{Result, SentryCliError};
+use crate::output::parse_issue_fields;
+use serde::Serialize;
+use url::Url;
+
+/// Keys that can be read and written with `config get|set|unset`
+pub const KEYS: &[&str] = &[
+    "default_org",
+    "server_url",
+    "auth_token",
+    "token_storage",
+    "default_project",
+    "max_retries",
+    "dsn",
+    "issue_fields",
+];
+
+pub(super) const DEFAULT_SERVER_URL: &str = "https://sentry.io";
+
+/// Prefixes of organization and user auth tokens
+const TOKEN_PREFIXES: &[&str] = &["sntrys_", "sntryu_"];
+
+/// A value as seen by commands, after applying all config layers
+#[derive(Debug, Serialize)]
+pub struct ConfigValue {
+    pub key: &'static str,
+    pub value: Option<String>,
+    /// Environment variable, file or `default` the value comes from
+    pub origin: Option<String>,
+}
+
+/// Environment variable that overrides a key
+fn env_var(key: &str) -> Option<&'static str> {
+    match key {
+        "default_org" => Some("SENTRY_ORG"),
+        "server_url" => Some("SENTRY_SERVER_URL"),
+        "auth_token" => Some("SENTRY_AUTH_TOKEN"),
+        "default_project" => Some("SENTRY_PROJECT"),
+        "max_retries" => Some("SENTRY_MAX_RETRIES"),
+        "dsn" => Some("SENTRY_DSN"),
+        _ => None,
+    }
+}
+
+/// Resolve a key name to its canonical static form
+pub fn check_key(key: &str) -> Result<&'static str> {
+    KEYS.iter().copied().find(|k| *k == key).ok_or_else(|| {
+        SentryCliError::Validation(format!(
+            "Unknown config key: {}. Valid keys: {}",
+            key,
+            KEYS.join(", ")
+        ))
+    })
+}
+
+/// Check a value before it is written to the config
+pub fn validate(key: &str, value: &str) -> Result<()> {
+    match check_key(key)? {
+        "server_url" => validate_url(value),
+        "default_org" => validate_slug("organization", value),
+        // `issues list` accepts several comma-separated projects
+        "default_project" => value
+            .split(',')
+            .try_for_each(|slug| validate_slug("project", slug.trim())),
+        "auth_token" => validate_token(value),
+        "token_storage" => value.parse::<TokenStorage>().map(|_| ()),
+        "max_retries" => value.parse::<u32>().map(|_| ()).map_err(|_| {
+            SentryCliError::Validation(format!(
+                "Invalid max_retries: {}. Expected a non-negative integer",
+                value
+            ))
+        }),
+        "dsn" => value.parse::<Dsn>().map(|_| ()),
+        "issue_fields" => parse_issue_fields(value).map(|_| ()),
+        _ => Ok(()),
+    }
+}
+
+fn validate_url(value: &str) -> Result<()> {
+    let url = Url::parse(value)
+        .map_err(|e| SentryCliError::Validation(format!("Invalid server_url: {}: {}", value, e)))?;
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        return Err(SentryCliError::Validation(format!(
+            "Invalid server_url: {}. Expected an http(s) URL such as https://sentry.example.com",
+            value
+        )));
+    }
+    Ok(())
+}
+
+/// Slugs are lowercase letters, digits, `-` and `_`, and not purely numeric
+fn validate_slug(kind: &str, value: &str) -> Result<()> {
+    let valid_chars = value
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    let numeric = value.chars().all(|c| c.is_ascii_digit());
+
+    if value.is_empty() || value.len() > 50 || !valid_chars || numeric {
+        return Err(SentryCliError::Validation(format!(
+            "Invalid {} slug: {}. Slugs use lowercase letters, digits, '-' and '_'",
+            kind, value
+        )));
+    }
+    Ok(())
+}
+
+/// Accept organization (`sntrys_`) and user (`sntryu_`) tokens, and the
+/// 64-digit hex tokens issued by older servers
+fn validate_token(value: &str) -> Result<()> {
+    let prefixed = TOKEN_PREFIXES.iter().any(|p| value.starts_with(p));
+    let legacy = value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit());
+
+    if !prefixed && !legacy {
+        return Err(SentryCliError::Validation(
+            "Invalid auth_token. Expected a token starting with sntrys_ or sntryu_".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl Settings {
+    pub fn get(&self, key: &str) -> Option<String> {
+        match key {
+            "default_org" => self.default_org.clone(),
+            "server_url" => self.server_url.clone(),
+            "auth_token" => self.auth_token.clone(),
+            "token_storage" => self.token_storage.map(|s| s.to_string()),
+            "default_project" => self.default_project.clone(),
+            "max_retries" => self.max_retries.map(|n| n.to_string()),
+            "dsn" => self.dsn.clone(),
+            "issue_fields" => self.issue_fields.clone(),
+            _ => None,
+        }
+    }
+
+    /// Validate and set a value. `auth_token` is written as given; use
+    /// `store_auth_token` to keep it out of the config file.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        validate(key, value)?;
+        let text = Some(value.to_string());
+
+        match key {
+            "default_org" => self.default_org = text,
+            "server_url" => self.server_url = text,
+            "auth_token" => self.auth_token = text,
+            "token_storage" => self.token_storage = Some(value.parse()?),
+            "default_project" => self.default_project = text,
+            "max_retries" => self.max_retries = Some(value.parse().unwrap_or_default()),
+            "dsn" => self.dsn = text,
+            "issue_fields" => self.issue_fields = text,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn unset(&mut self, key: &str) {
+        match key {
+            "default_org" => self.default_org = None,
+            "server_url" => self.server_url = None,
+            "auth_token" => self.auth_token = None,
+            "token_storage" => self.token_storage = None,
+            "default_project" => self.default_project = None,
+            "max_retries" => self.max_retries = None,
+            "dsn" => self.dsn = None,
+            "issue_fields" => self.issue_fields = None,
+            _ => {}
+        }
+    }
+
+    /// Check every value that is set, e.g. before saving a new profile
+    pub fn validate(&self) -> Result<()> {
+        for key in KEYS {
+            if let Some(value) = self.get(key) {
+                validate(key, &value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Config {
+    /// The effective value of a key and where it comes from. The auth
+    /// token is looked up in its secret store; if that fails, the value is
+    /// empty and the origin explains why.
+    pub fn value(&self, key: &str) -> Result<ConfigValue> {
+        let key = check_key(key)?;
+
+        if key == "auth_token" {
+            let (value, origin) = match self.resolve_auth_token(None) {
+                Ok((token, source)) => (Some(token), Some(source.to_string())),
+                Err(SentryCliError::Auth(_)) => (None, None),
+                Err(e) => (None, Some(format!("unavailable: {}", e))),
+            };
+            return Ok(ConfigValue { key, value, origin });
+        }
+
+        if let Some(env) = env_var(key) {
+            if let Ok(value) = std::env::var(env) {
+                return Ok(ConfigValue {
+                    key,
+                    value: Some(value),
+                    origin: Some(env.to_string()),
+                });
+            }
+        }
+
+        if key == "server_url" {
+            let local = match self.resolve_auth_token(None) {
+                Ok((_, source)) => self.local_server_url(&source),
+                Err(_) => None,
+            };
+            if let Some((path, url)) = local {
+                return Ok(ConfigValue {
+                    key,
+                    value: Some(url),
+                    origin: Some(path.display().to_string()),
+                });
+            }
+        }
+
+        if let Some(value) = self.settings.get(key) {
+            return Ok(ConfigValue {
+                key,
+                value: Some(value),
+                origin: Some(self.origin(key).display().to_string()),
+            });
+        }
+
+        let default = match key {
+            "server_url" => Some(DEFAULT_SERVER_URL.to_string()),
+            "max_retries" => Some(DEFAULT_MAX_RETRIES.to_string()),
+            _ => None,
+        };
+        Ok(ConfigValue {
+            key,
+            origin: default.as_ref().map(|_| "default".to_string()),
+            value: default,
+        })
+    }
+}
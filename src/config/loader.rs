@@ -1,51 +1,358 @@
+use super::keys::DEFAULT_SERVER_URL;
+use super::local;
+use super::secrets::{self, TokenSource, TokenStorage};
+use crate::api::retry::DEFAULT_MAX_RETRIES;
 use crate::error::{Result, SentryCliError};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
 use std::path::PathBuf;
 
-#[derive(Debug, Default, Deserialize, Serialize)]
-pub struct Config {
+/// Profile name that refers to the top-level settings
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Settings that can be given at the top level of the config file or per
+/// profile in `[profiles.<name>]`
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Settings {
     pub default_org: Option<String>,
     pub server_url: Option<String>,
     pub auth_token: Option<String>,
     pub default_project: Option<String>,
+    pub max_retries: Option<u32>,
+    pub dsn: Option<String>,
+    /// Default columns of the issues table, comma-separated
+    pub issue_fields: Option<String>,
+    /// Where the auth token is kept when it is not in this file
+    pub token_storage: Option<TokenStorage>,
+}
+
+impl Settings {
+    /// Store a token outside the config file, in the configured storage.
+    /// Without one, the OS keyring is tried first and the credentials file
+    /// used if it is unavailable. Returns where the token was stored.
+    pub fn store_auth_token(&mut self, profile: &str, token: &str) -> Result<TokenStorage> {
+        let storage = match self.token_storage {
+            Some(storage) => {
+                secrets::store_token(profile, token, storage)?;
+                storage
+            }
+            None => match secrets::store_token(profile, token, TokenStorage::Keyring) {
+                Ok(()) => TokenStorage::Keyring,
+                Err(e) => {
+                    eprintln!(
+                        "Warning: {}. Storing token in {} instead.",
+                        e,
+                        secrets::credentials_path().display()
+                    );
+                    secrets::store_token(profile, token, TokenStorage::File)?;
+                    TokenStorage::File
+                }
+            },
+        };
+
+        self.token_storage = Some(storage);
+        self.auth_token = None;
+        Ok(storage)
+    }
+
+    /// Remove the token from the config file and any secret store
+    pub fn delete_auth_token(&mut self, profile: &str) -> Result<()> {
+        if let Some(storage) = self.token_storage {
+            secrets::delete_token(profile, storage)?;
+        }
+        self.auth_token = None;
+        Ok(())
+    }
+
+    /// Names of the values that are set
+    fn keys(&self) -> Vec<&'static str> {
+        [
+            ("default_org", self.default_org.is_some()),
+            ("server_url", self.server_url.is_some()),
+            ("auth_token", self.auth_token.is_some()),
+            ("default_project", self.default_project.is_some()),
+            ("max_retries", self.max_retries.is_some()),
+            ("dsn", self.dsn.is_some()),
+            ("issue_fields", self.issue_fields.is_some()),
+            ("token_storage", self.token_storage.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(key, set)| set.then_some(key))
+        .collect()
+    }
+
+    /// Fill unset values from `fallback`
+    pub fn or(self, fallback: Settings) -> Settings {
+        Settings {
+            default_org: self.default_org.or(fallback.default_org),
+            server_url: self.server_url.or(fallback.server_url),
+            auth_token: self.auth_token.or(fallback.auth_token),
+            default_project: self.default_project.or(fallback.default_project),
+            max_retries: self.max_retries.or(fallback.max_retries),
+            dsn: self.dsn.or(fallback.dsn),
+            issue_fields: self.issue_fields.or(fallback.issue_fields),
+            token_storage: self.token_storage.or(fallback.token_storage),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(from = "ConfigFile")]
+pub struct Config {
+    #[serde(flatten)]
+    pub settings: Settings,
+    /// Profile used when neither --profile nor SENTRY_PROFILE is given
+    pub active_profile: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Settings>,
+    /// Profile applied by `with_profile`, whose stored token is in effect
+    #[serde(skip)]
+    token_profile: Option<String>,
+    /// Project config files applied over the user config, outermost first
+    #[serde(skip)]
+    pub local_files: Vec<PathBuf>,
+    /// Project config file each overridden value was read from
+    #[serde(skip)]
+    origins: BTreeMap<&'static str, PathBuf>,
+    /// `server_url` of the nearest project file that sets one together with
+    /// a token, and that file. Only used with that file's token.
+    #[serde(skip)]
+    local_server_url: Option<(PathBuf, String)>,
+}
+
+/// The config file as read. Same as `Config`, but without
+/// `#[serde(flatten)]`, which would make parse errors point at the start of
+/// the file instead of the offending value.
+#[derive(Deserialize)]
+struct ConfigFile {
+    default_org: Option<String>,
+    server_url: Option<String>,
+    auth_token: Option<String>,
+    default_project: Option<String>,
+    max_retries: Option<u32>,
+    dsn: Option<String>,
+    issue_fields: Option<String>,
+    token_storage: Option<TokenStorage>,
+    active_profile: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, Settings>,
+}
+
+impl From<ConfigFile> for Config {
+    fn from(file: ConfigFile) -> Self {
+        Config {
+            settings: Settings {
+                default_org: file.default_org,
+                server_url: file.server_url,
+                auth_token: file.auth_token,
+                default_project: file.default_project,
+                max_retries: file.max_retries,
+                dsn: file.dsn,
+                issue_fields: file.issue_fields,
+                token_storage: file.token_storage,
+            },
+            active_profile: file.active_profile,
+            profiles: file.profiles,
+            ..Default::default()
+        }
+    }
 }
 
 impl Config {
-    /// Get auth token with priority: CLI flag > env var > config file
-    pub fn get_auth_token(&self, cli_override: Option<&str>) -> Result<String> {
+    /// Get profile name with priority: CLI flag > env var > config file
+    pub fn get_profile(&self, cli_override: Option<&str>) -> Option<String> {
         cli_override
             .map(String::from)
-            .or_else(|| std::env::var("SENTRY_AUTH_TOKEN").ok())
-            .or_else(|| self.auth_token.clone())
-            .ok_or_else(|| {
-                SentryCliError::Auth(
-                    "No auth token found. Set SENTRY_AUTH_TOKEN or configure in config file".into(),
-                )
-            })
+            .or_else(|| std::env::var("SENTRY_PROFILE").ok())
+            .or_else(|| self.active_profile.clone())
+            .filter(|name| name != DEFAULT_PROFILE)
     }
 
-    /// Get server URL with priority: CLI flag > env var > config file > default
-    pub fn get_server_url(&self, cli_override: Option<&str>) -> String {
+    /// Apply the selected profile: its settings take precedence over the
+    /// top-level ones, which remain as fallback. The auth token is never
+    /// inherited, as it may belong to another account or server.
+    pub fn with_profile(mut self, cli_override: Option<&str>) -> Result<Self> {
+        let Some(name) = self.get_profile(cli_override) else {
+            return Ok(self);
+        };
+        let profile = self.profiles.get(&name).cloned().ok_or_else(|| {
+            SentryCliError::Config(format!(
+                "Profile '{}' not found. Run 'sentry config profiles list' to see available profiles",
+                name
+            ))
+        })?;
+
+        self.token_profile = Some(name);
+        let (auth_token, token_storage) = (profile.auth_token.clone(), profile.token_storage);
+        self.settings = Settings {
+            auth_token,
+            token_storage,
+            ..profile.or(self.settings)
+        };
+        Ok(self)
+    }
+
+    /// Layer project config files (`.sentryclirc`, `sentry.toml`) found
+    /// from the current directory upwards over the user config. Nearer files
+    /// take precedence; environment variables and flags still override them.
+    ///
+    /// A project file may come from someone else's checkout, so it cannot
+    /// choose where the token is read from, nor send it to another server:
+    /// its `server_url` is only used if the token in use is the one the same
+    /// file supplies (see `get_server_url`).
+    pub fn with_local_config(mut self) -> Result<Self> {
+        let cwd = std::env::current_dir()?;
+        for (path, mut layer) in local::discover(&cwd)? {
+            if path == config_path() {
+                continue;
+            }
+            if layer.token_storage.take().is_some() {
+                eprintln!("Warning: ignoring token_storage in {}", path.display());
+            }
+            if let Some(url) = layer.server_url.take() {
+                if layer.auth_token.is_some() {
+                    self.local_server_url = Some((path.clone(), url));
+                } else {
+                    eprintln!(
+                        "Warning: ignoring server_url in {}, which sets no auth token. \
+                         Use --server or SENTRY_SERVER_URL to use that server",
+                        path.display()
+                    );
+                }
+            }
+            for key in layer.keys() {
+                self.origins.insert(key, path.clone());
+            }
+            self.settings = layer.or(self.settings);
+            self.local_files.push(path);
+        }
+        Ok(self)
+    }
+
+    /// The file a config value was read from
+    pub fn origin(&self, key: &str) -> PathBuf {
+        self.origins.get(key).cloned().unwrap_or_else(config_path)
+    }
+
+    /// Profile name the stored auth token is kept under. Only the selected
+    /// profile's own entry is read, never that of the default profile.
+    pub fn token_profile(&self) -> &str {
+        self.token_profile.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Get auth token and where it was found. A token kept in the keyring
+    /// or a credentials file is loaded on demand.
+    pub fn resolve_auth_token(&self, cli_override: Option<&str>) -> Result<(String, TokenSource)> {
+        if let Some(token) = cli_override {
+            return Ok((token.to_string(), TokenSource::Flag));
+        }
+        if let Ok(token) = std::env::var("SENTRY_AUTH_TOKEN") {
+            return Ok((token, TokenSource::Env));
+        }
+        if let Some(token) = &self.settings.auth_token {
+            return Ok((
+                token.clone(),
+                TokenSource::ConfigFile(self.origin("auth_token")),
+            ));
+        }
+        if let Some(storage) = self.settings.token_storage {
+            if let Some(token) = secrets::load_token(self.token_profile(), storage)? {
+                return Ok((token, TokenSource::Stored(storage)));
+            }
+        }
+        Err(SentryCliError::Auth(
+            "No auth token found. Set SENTRY_AUTH_TOKEN or configure in config file".into(),
+        ))
+    }
+
+    /// Get server URL with priority: CLI flag > env var > project file >
+    /// user config file > default. `token` is where the auth token in use
+    /// comes from, as returned by `resolve_auth_token`.
+    pub fn get_server_url(&self, cli_override: Option<&str>, token: &TokenSource) -> String {
         cli_override
             .map(String::from)
             .or_else(|| std::env::var("SENTRY_SERVER_URL").ok())
-            .or_else(|| self.server_url.clone())
-            .unwrap_or_else(|| "https://sentry.io".to_string())
+            .or_else(|| self.local_server_url(token).map(|(_, url)| url))
+            .or_else(|| self.settings.server_url.clone())
+            .unwrap_or_else(|| DEFAULT_SERVER_URL.to_string())
+    }
+
+    /// The `server_url` of a project file and that file, if the token in
+    /// use is the one from the same file. Another token, e.g. one from
+    /// SENTRY_AUTH_TOKEN, is never sent to a server a checkout chose.
+    pub fn local_server_url(&self, token: &TokenSource) -> Option<(PathBuf, String)> {
+        let (path, url) = self.local_server_url.as_ref()?;
+        if *token == TokenSource::ConfigFile(path.clone()) {
+            return Some((path.clone(), url.clone()));
+        }
+        eprintln!(
+            "Warning: ignoring server_url in {}, as the auth token in use is not the one it sets. \
+             Use --server or SENTRY_SERVER_URL to use that server",
+            path.display()
+        );
+        None
     }
 
     /// Get organization with priority: CLI flag > env var > config file
-    pub fn get_org(&self, cli_override: Option<&str>) -> Result<String> {
+    pub fn get_org(&self, cli_override: Option<&str>) -> Option<String> {
         cli_override
             .map(String::from)
             .or_else(|| std::env::var("SENTRY_ORG").ok())
-            .or_else(|| self.default_org.clone())
+            .or_else(|| self.settings.default_org.clone())
+    }
+
+    /// Get project with priority: CLI flag > env var > config file
+    pub fn get_project(&self, cli_override: Option<&str>) -> Option<String> {
+        cli_override
+            .map(String::from)
+            .or_else(|| std::env::var("SENTRY_PROJECT").ok())
+            .or_else(|| self.settings.default_project.clone())
+    }
+
+    /// Like `get_project`, for commands that cannot run without a project
+    pub fn require_project(&self, cli_override: Option<&str>) -> Result<String> {
+        self.get_project(cli_override).ok_or_else(|| {
+            SentryCliError::Config(
+                "No project specified. Use --project, set SENTRY_PROJECT or configure default_project"
+                    .into(),
+            )
+        })
+    }
+
+    /// Get the issues table columns with priority: CLI flag > config file
+    pub fn get_issue_fields(&self, cli_override: Option<&str>) -> Option<String> {
+        cli_override
+            .map(String::from)
+            .or_else(|| self.settings.issue_fields.clone())
+    }
+
+    /// Get DSN with priority: CLI flag > env var > config file
+    pub fn get_dsn(&self, cli_override: Option<&str>) -> Result<String> {
+        cli_override
+            .map(String::from)
+            .or_else(|| std::env::var("SENTRY_DSN").ok())
+            .or_else(|| self.settings.dsn.clone())
             .ok_or_else(|| {
                 SentryCliError::Config(
-                    "No organization specified. Use --org or configure default_org".into(),
+                    "No DSN specified. Use --dsn, set SENTRY_DSN or configure dsn".into(),
                 )
             })
     }
+
+    /// Get max retries with priority: CLI flag > env var > config file > default
+    pub fn get_max_retries(&self, cli_override: Option<u32>) -> Result<u32> {
+        if let Some(retries) = cli_override {
+            return Ok(retries);
+        }
+        if let Ok(value) = std::env::var("SENTRY_MAX_RETRIES") {
+            return value.parse().map_err(|_| {
+                SentryCliError::Config(format!("Invalid SENTRY_MAX_RETRIES value: {}", value))
+            });
+        }
+        Ok(self.settings.max_retries.unwrap_or(DEFAULT_MAX_RETRIES))
+    }
 }
 
 /// Get the path to the config file
@@ -56,14 +363,28 @@ pub fn config_path() -> PathBuf {
 }
 
 /// Load configuration from file (if exists)
-pub fn load_config() -> Config {
+pub fn load_config() -> Result<Config> {
     let path = config_path();
-    if path.exists() {
-        match std::fs::read_to_string(&path) {
-            Ok(content) => toml::from_str(&content).unwrap_or_default(),
-            Err(_) => Config::default(),
-        }
-    } else {
-        Config::default()
+    if !path.exists() {
+        return Ok(Config::default());
     }
+
+    let content = fs::read_to_string(&path)?;
+    toml::from_str(&content).map_err(|e| {
+        SentryCliError::Config(format!("Invalid config file {}: {}", path.display(), e))
+    })
+}
+
+/// Write configuration to the config file
+pub fn save_config(config: &Config) -> Result<()> {
+    let path = config_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let content = toml::to_string_pretty(config)
+        .map_err(|e| SentryCliError::Config(format!("Failed to serialize config: {}", e)))?;
+
+    fs::write(&path, content)?;
+    Ok(())
 }
@@ -0,0 +1,109 @@
+use super::Settings;
+use crate::error::{Result, SentryCliError};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Project config file names, in increasing precedence within a directory
+const LOCAL_FILES: &[&str] = &[".sentryclirc", "sentry.toml"];
+
+/// Find project config files from `start` up to the filesystem root and
+/// parse them. The result is ordered from the outermost directory inwards,
+/// so that applying the layers in order lets nearer files win.
+pub fn discover(start: &Path) -> Result<Vec<(PathBuf, Settings)>> {
+    let mut layers = Vec::new();
+
+    for dir in start.ancestors() {
+        for name in LOCAL_FILES.iter().rev() {
+            let path = dir.join(name);
+            if path.is_file() {
+                let settings = parse(&path)?;
+                layers.push((path, settings));
+            }
+        }
+    }
+
+    layers.reverse();
+    Ok(layers)
+}
+
+fn parse(path: &Path) -> Result<Settings> {
+    let content = fs::read_to_string(path)?;
+
+    if path.extension().is_some_and(|ext| ext == "toml") {
+        toml::from_str(&content).map_err(|e| {
+            SentryCliError::Config(format!("Invalid config file {}: {}", path.display(), e))
+        })
+    } else {
+        parse_sentryclirc(&content, path)
+    }
+}
+
+/// Parse the INI format used by `.sentryclirc`:
+///
+/// ```ini
+/// [auth]
+/// token = sntrys_...
+/// dsn = https://key@o0.ingest.sentry.io/42
+///
+/// [defaults]
+/// url = https://sentry.example.com
+/// org = acme
+/// project = backend
+///
+/// [http]
+/// max_retries = 5
+/// ```
+///
+/// Unknown sections and keys are ignored.
+fn parse_sentryclirc(content: &str, path: &Path) -> Result<Settings> {
+    let mut settings = Settings::default();
+    let mut section = String::new();
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+
+        let invalid = || {
+            SentryCliError::Config(format!(
+                "Invalid line {} in {}: {}",
+                number + 1,
+                path.display(),
+                line
+            ))
+        };
+
+        if let Some(name) = line.strip_prefix('[') {
+            section = name
+                .strip_suffix(']')
+                .ok_or_else(invalid)?
+                .trim()
+                .to_string();
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+        let value = value.trim();
+
+        match (section.as_str(), key.trim()) {
+            ("auth", "token") => settings.auth_token = Some(value.to_string()),
+            ("auth", "dsn") => settings.dsn = Some(value.to_string()),
+            ("defaults", "url") => settings.server_url = Some(value.to_string()),
+            ("defaults", "org") => settings.default_org = Some(value.to_string()),
+            ("defaults", "project") => settings.default_project = Some(value.to_string()),
+            ("http", "max_retries") => {
+                settings.max_retries = Some(value.parse().map_err(|_| {
+                    SentryCliError::Config(format!(
+                        "Invalid max_retries on line {} in {}",
+                        number + 1,
+                        path.display()
+                    ))
+                })?)
+            }
+            _ => {}
+        }
+    }
+
+    Ok(settings)
+}
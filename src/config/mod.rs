@@ -1,3 +1,7 @@
+mod keys;
 mod loader;
+mod local;
+pub mod secrets;
 
-pub use loader::{config_path, load_config, Config};
+pub use keys::{check_key, validate, ConfigValue, KEYS};
+pub use loader::{config_path, load_config, save_config, Config, Settings, DEFAULT_PROFILE};
@@ -0,0 +1,301 @@
+use super::config_path;
+use crate::error::{Result, SentryCliError};
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const KEYRING_SERVICE: &str = "sentry-cli";
+const ENCRYPTED_MAGIC: &[u8] = b"SNTRYCRED1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Where an auth token is kept outside of the config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenStorage {
+    /// OS keyring: Secret Service on Linux, Keychain on macOS, Credential Manager on Windows
+    Keyring,
+    /// `credentials.toml` next to the config file, readable only by the owner
+    File,
+    /// `credentials.enc`, encrypted with a passphrase
+    Encrypted,
+}
+
+impl fmt::Display for TokenStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenStorage::Keyring => write!(f, "keyring"),
+            TokenStorage::File => write!(f, "file"),
+            TokenStorage::Encrypted => write!(f, "encrypted"),
+        }
+    }
+}
+
+impl FromStr for TokenStorage {
+    type Err = SentryCliError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "keyring" => Ok(TokenStorage::Keyring),
+            "file" => Ok(TokenStorage::File),
+            "encrypted" => Ok(TokenStorage::Encrypted),
+            _ => Err(SentryCliError::Validation(format!(
+                "Invalid token_storage: {}. Expected keyring, file or encrypted",
+                s
+            ))),
+        }
+    }
+}
+
+/// Where the auth token in use was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    Flag,
+    Env,
+    /// Plaintext `auth_token` in a config file
+    ConfigFile(PathBuf),
+    Stored(TokenStorage),
+}
+
+impl fmt::Display for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenSource::Flag => write!(f, "--token flag"),
+            TokenSource::Env => write!(f, "SENTRY_AUTH_TOKEN"),
+            TokenSource::ConfigFile(path) => write!(f, "{}, plaintext", path.display()),
+            TokenSource::Stored(TokenStorage::Keyring) => write!(f, "OS keyring"),
+            TokenSource::Stored(TokenStorage::File) => {
+                write!(f, "{}", credentials_path().display())
+            }
+            TokenSource::Stored(TokenStorage::Encrypted) => {
+                write!(f, "{}, encrypted", encrypted_path().display())
+            }
+        }
+    }
+}
+
+/// Store a profile's token in the given storage
+pub fn store_token(profile: &str, token: &str, storage: TokenStorage) -> Result<()> {
+    match storage {
+        TokenStorage::Keyring => keyring(profile, |entry| entry.set_password(token))
+            .map_err(|e| credentials_error("OS keyring", e)),
+        TokenStorage::File => {
+            let mut tokens = read_credentials_file()?;
+            tokens.insert(profile.to_string(), token.to_string());
+            write_credentials_file(&tokens)
+        }
+        TokenStorage::Encrypted => {
+            let passphrase = passphrase()?;
+            let mut tokens = read_encrypted(&passphrase)?;
+            tokens.insert(profile.to_string(), token.to_string());
+            write_encrypted(&tokens, &passphrase)
+        }
+    }
+}
+
+pub fn load_token(profile: &str, storage: TokenStorage) -> Result<Option<String>> {
+    match storage {
+        TokenStorage::Keyring => match keyring(profile, keyring::Entry::get_password) {
+            Ok(token) => Ok(Some(token)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(credentials_error("OS keyring", e)),
+        },
+        TokenStorage::File => Ok(read_credentials_file()?.remove(profile)),
+        TokenStorage::Encrypted => Ok(read_encrypted(&passphrase()?)?.remove(profile)),
+    }
+}
+
+pub fn delete_token(profile: &str, storage: TokenStorage) -> Result<()> {
+    match storage {
+        TokenStorage::Keyring => match keyring(profile, keyring::Entry::delete_credential) {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(credentials_error("OS keyring", e)),
+        },
+        TokenStorage::File => {
+            let mut tokens = read_credentials_file()?;
+            if tokens.remove(profile).is_some() {
+                write_credentials_file(&tokens)?;
+            }
+            Ok(())
+        }
+        TokenStorage::Encrypted => {
+            let passphrase = passphrase()?;
+            let mut tokens = read_encrypted(&passphrase)?;
+            if tokens.remove(profile).is_some() {
+                write_encrypted(&tokens, &passphrase)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Run a keyring operation on its own thread. The Secret Service backend
+/// blocks on a runtime of its own, which cannot be nested in ours.
+fn keyring<T: Send>(
+    profile: &str,
+    op: impl FnOnce(&keyring::Entry) -> keyring::Result<T> + Send,
+) -> keyring::Result<T> {
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| op(&keyring::Entry::new(KEYRING_SERVICE, profile)?))
+            .join()
+            .unwrap_or_else(|_| {
+                Err(keyring::Error::PlatformFailure(
+                    "keyring thread panicked".into(),
+                ))
+            })
+    })
+}
+
+fn credentials_error(store: &str, e: impl fmt::Display) -> SentryCliError {
+    SentryCliError::Credentials(format!("{} unavailable: {}", store, e))
+}
+
+pub fn credentials_path() -> PathBuf {
+    config_path().with_file_name("credentials.toml")
+}
+
+pub fn encrypted_path() -> PathBuf {
+    config_path().with_file_name("credentials.enc")
+}
+
+fn read_credentials_file() -> Result<BTreeMap<String, String>> {
+    let path = credentials_path();
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    parse_credentials(&fs::read(&path)?, &path)
+}
+
+fn write_credentials_file(tokens: &BTreeMap<String, String>) -> Result<()> {
+    write_private(
+        &credentials_path(),
+        serialize_credentials(tokens)?.as_bytes(),
+    )
+}
+
+fn parse_credentials(content: &[u8], path: &Path) -> Result<BTreeMap<String, String>> {
+    let content = String::from_utf8_lossy(content);
+    toml::from_str(&content).map_err(|e| {
+        SentryCliError::Credentials(format!("Invalid credentials in {}: {}", path.display(), e))
+    })
+}
+
+fn serialize_credentials(tokens: &BTreeMap<String, String>) -> Result<String> {
+    toml::to_string(tokens)
+        .map_err(|e| SentryCliError::Credentials(format!("Failed to serialize credentials: {}", e)))
+}
+
+/// Write a file only the current user can read
+fn write_private(path: &Path, content: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        // The mode only applies to newly created files
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        file.write_all(content)?;
+    }
+    #[cfg(not(unix))]
+    fs::write(path, content)?;
+
+    Ok(())
+}
+
+/// Passphrase for the encrypted store, from SENTRY_CREDENTIALS_PASSPHRASE
+/// or an interactive prompt
+fn passphrase() -> Result<String> {
+    if let Ok(passphrase) = std::env::var("SENTRY_CREDENTIALS_PASSPHRASE") {
+        return Ok(passphrase);
+    }
+    if !io::stdin().is_terminal() {
+        return Err(SentryCliError::Credentials(
+            "Tokens are stored encrypted. Set SENTRY_CREDENTIALS_PASSPHRASE to unlock them"
+                .to_string(),
+        ));
+    }
+    Ok(rpassword::prompt_password("Credentials passphrase: ")?)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Key> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| SentryCliError::Credentials(format!("Key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+/// Encrypted file layout: magic | salt | nonce | ChaCha20-Poly1305 ciphertext
+fn read_encrypted(passphrase: &str) -> Result<BTreeMap<String, String>> {
+    let path = encrypted_path();
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let data = fs::read(&path)?;
+    let invalid = || {
+        SentryCliError::Credentials(format!(
+            "{} is not a valid credentials file",
+            path.display()
+        ))
+    };
+    let rest = data.strip_prefix(ENCRYPTED_MAGIC).ok_or_else(invalid)?;
+    if rest.len() < SALT_LEN + NONCE_LEN {
+        return Err(invalid());
+    }
+    let (salt, rest) = rest.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, salt)?);
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| {
+            SentryCliError::Credentials(format!(
+                "Could not decrypt {}: wrong passphrase?",
+                path.display()
+            ))
+        })?;
+
+    parse_credentials(&plaintext, &path)
+}
+
+fn write_encrypted(tokens: &BTreeMap<String, String>, passphrase: &str) -> Result<()> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::rng().fill(&mut salt);
+    rand::rng().fill(&mut nonce);
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt)?);
+    let ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            serialize_credentials(tokens)?.as_bytes(),
+        )
+        .map_err(|_| SentryCliError::Credentials("Encryption failed".to_string()))?;
+
+    let mut data = ENCRYPTED_MAGIC.to_vec();
+    data.extend_from_slice(&salt);
+    data.extend_from_slice(&nonce);
+    data.extend_from_slice(&ciphertext);
+
+    write_private(&encrypted_path(), &data)
+}
@@ -26,6 +26,15 @@ pub enum SentryCliError {
     #[error("Rate limited. Retry after {retry_after} seconds")]
     RateLimited { retry_after: u64 },
 
+    #[error("Processing error: {0}")]
+    Processing(String),
+
+    #[error("Git error: {0}")]
+    Git(String),
+
+    #[error("Credential storage error: {0}")]
+    Credentials(String),
+
     #[error("URL parse error: {0}")]
     UrlParse(#[from] url::ParseError),
 
@@ -0,0 +1,118 @@
+use crate::error::{Result, SentryCliError};
+use chrono::{DateTime, Utc};
+use std::process::Command;
+
+/// A commit read from the local repository
+#[derive(Debug)]
+pub struct GitCommit {
+    pub id: String,
+    pub author_name: String,
+    pub author_email: String,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub message: String,
+}
+
+// Unit and record separators keep multi-line commit messages intact
+const FIELD_SEP: char = '\u{1f}';
+const RECORD_SEP: char = '\u{1e}';
+
+fn git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| SentryCliError::Git(format!("Failed to run git: {}", e)))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(SentryCliError::Git(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            stderr.trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The full SHA of the current HEAD
+pub fn head_commit() -> Result<String> {
+    git(&["rev-parse", "HEAD"])
+}
+
+/// Derive a Sentry repository name (`owner/repo`) from the given remote's URL
+pub fn repository_name(remote: &str) -> Result<String> {
+    let url = git(&["remote", "get-url", remote])?;
+    parse_repository_name(&url).ok_or_else(|| {
+        SentryCliError::Git(format!(
+            "Could not determine repository name from remote URL: {}",
+            url
+        ))
+    })
+}
+
+fn parse_repository_name(url: &str) -> Option<String> {
+    let url = url.trim_end_matches('/').trim_end_matches(".git");
+    // Handles https://host/owner/repo, ssh://git@host:22/owner/repo and git@host:owner/repo
+    let without_scheme = url.rsplit_once("://").map_or(url, |(_, rest)| rest);
+    let (_, path) = without_scheme.split_once(['/', ':'])?;
+    let path = match path.split_once('/') {
+        Some((port, rest)) if port.chars().all(|c| c.is_ascii_digit()) => rest,
+        _ => path,
+    };
+    path.contains('/').then(|| path.to_string())
+}
+
+/// Commits reachable from `head` but not from `since`, newest first.
+/// Without `since`, at most `limit` commits are returned. With it, all of
+/// them are, and `since` must be in the local history: in a shallow clone
+/// that lacks it, the range would silently include too many commits.
+pub fn commits_between(since: Option<&str>, head: &str, limit: usize) -> Result<Vec<GitCommit>> {
+    let format = format!(
+        "--format=%H{0}%an{0}%ae{0}%aI{0}%B{1}",
+        FIELD_SEP, RECORD_SEP
+    );
+    let output = match since {
+        Some(since) => {
+            check_in_history(since, head)?;
+            git(&["log", &format, &format!("{}..{}", since, head)])?
+        }
+        None => git(&["log", &format, &format!("--max-count={}", limit), head])?,
+    };
+
+    Ok(output
+        .split(RECORD_SEP)
+        .filter_map(|record| {
+            let mut fields = record.trim().splitn(5, FIELD_SEP);
+            let id = fields.next().filter(|s| !s.is_empty())?.to_string();
+            Some(GitCommit {
+                id,
+                author_name: fields.next()?.to_string(),
+                author_email: fields.next()?.to_string(),
+                timestamp: DateTime::parse_from_rfc3339(fields.next()?)
+                    .ok()
+                    .map(|d| d.with_timezone(&Utc)),
+                message: fields.next().unwrap_or_default().trim().to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Fail unless `commit` exists locally and, in a shallow clone, is an
+/// ancestor of `head`, so that the history between them is complete
+fn check_in_history(commit: &str, head: &str) -> Result<()> {
+    let missing = || {
+        SentryCliError::Git(format!(
+            "Commit {} of the previous release is not in the local history. \
+             If this is a shallow clone, fetch more of it, e.g. with 'git fetch --unshallow'",
+            commit
+        ))
+    };
+
+    git(&["cat-file", "-e", &format!("{}^{{commit}}", commit)]).map_err(|_| missing())?;
+    if git(&["rev-parse", "--is-shallow-repository"])? == "true"
+        && git(&["merge-base", "--is-ancestor", commit, head]).is_err()
+    {
+        return Err(missing());
+    }
+    Ok(())
+}
@@ -2,14 +2,23 @@ mod api;
 mod cli;
 mod config;
 mod error;
+mod git;
 mod output;
+mod upload;
 
 use std::error::Error;
 
 use clap::{CommandFactory, Parser};
 use clap_complete::generate;
-use cli::args::{Cli, Commands, ConfigCommands, IssuesCommands};
-use cli::commands::{config as config_cmd, issues};
+use cli::args::{
+    AuthCommands, Cli, Commands, ConfigCommands, DebugFilesCommands, DeploysCommands,
+    EventsCommands, IssuesCommands, MonitorsCommands, OrgsCommands, ProfilesCommands,
+    ProjectKeysCommands, ProjectsCommands, ReleasesCommands, SourcemapsCommands, TeamsCommands,
+};
+use cli::commands::{
+    auth, config as config_cmd, debug_files, events, issues, monitors, orgs, projects, releases,
+    send, sourcemaps, teams,
+};
 use config::load_config;
 use output::print_error;
 
@@ -33,44 +42,138 @@ async fn main() {
 
 async fn run() -> error::Result<()> {
     let cli = Cli::parse();
-    let config = load_config();
+    let profile = cli.profile.as_deref();
 
     // Set global output format and quiet mode
     output::set_format(cli.format);
     output::set_quiet(cli.quiet);
+    if let Some(expr) = &cli.jq {
+        output::set_jq(expr)?;
+    }
+    if let Some(path) = &cli.template_file {
+        let source = std::fs::read_to_string(path).map_err(|e| {
+            error::SentryCliError::Validation(format!(
+                "Cannot read template file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        output::set_template(&source)?;
+    } else if let Some(source) = &cli.template {
+        output::set_template(source)?;
+    }
+
+    // Profile selection is resolved on use, so that `config` commands keep
+    // working when the selected profile does not exist
+    let load_profile = || load_config()?.with_profile(profile)?.with_local_config();
+
+    // Project-scoped commands fall back to SENTRY_PROJECT and default_project
+    let get_project = |flag: Option<String>| -> error::Result<Option<String>> {
+        Ok(load_profile()?.get_project(flag.as_deref()))
+    };
+    let require_projects = |flags: Vec<String>| -> error::Result<Vec<String>> {
+        if !flags.is_empty() {
+            return Ok(flags);
+        }
+        Ok(vec![load_profile()?.require_project(None)?])
+    };
+
+    let create_client = || {
+        let config = load_profile()?;
+        api::SentryClient::new(
+            &config,
+            cli.org.as_deref(),
+            cli.server.as_deref(),
+            cli.token.as_deref(),
+            cli.max_retries,
+            cli.verbose,
+        )
+    };
+
+    let create_ingest_client = |dsn: Option<&str>| -> error::Result<api::IngestClient> {
+        let dsn = load_profile()?.get_dsn(dsn)?.parse()?;
+        Ok(api::IngestClient::new(dsn, cli.verbose))
+    };
 
     match cli.command {
         Commands::Issues { command } => {
-            let client = api::SentryClient::new(
-                &config,
-                cli.org.as_deref(),
-                cli.server.as_deref(),
-                cli.token.as_deref(),
-                cli.verbose,
-            )?;
+            let client = create_client()?;
 
             match command {
                 IssuesCommands::List {
                     project,
+                    all_projects,
                     status,
                     query,
                     sort,
                     limit,
                     all,
+                    max_results,
+                    cursor,
+                    fields,
+                    no_truncate,
+                    watch,
+                    interval,
+                    bell,
+                    notify,
                 } => {
                     let options = issues::ListOptions {
-                        project,
+                        project: if all_projects {
+                            None
+                        } else {
+                            get_project(project)?
+                        },
                         status,
                         query,
                         sort,
                         limit,
                         all,
+                        max_results,
+                        cursor,
                     };
-                    issues::list_issues(&client, options).await?;
+                    let fields = load_profile()?.get_issue_fields(fields.as_deref());
+                    let columns = output::IssueColumns::new(fields.as_deref(), !no_truncate)?;
+                    if watch {
+                        let watch = issues::WatchOptions {
+                            interval,
+                            bell,
+                            notify,
+                        };
+                        issues::watch_issues(&client, options, watch, &columns).await?;
+                    } else {
+                        issues::list_issues(&client, options, &columns).await?;
+                    }
+                }
+                IssuesCommands::Triage {
+                    project,
+                    all_projects,
+                    status,
+                    query,
+                    sort,
+                    limit,
+                } => {
+                    let options = issues::ListOptions {
+                        project: if all_projects {
+                            None
+                        } else {
+                            get_project(project)?
+                        },
+                        status: Some(status),
+                        query,
+                        sort,
+                        limit,
+                        all: false,
+                        max_results: None,
+                        cursor: None,
+                    };
+                    issues::triage_issues(&client, options).await?;
                 }
                 IssuesCommands::View { issue_id } => {
                     issues::view_issue(&client, &issue_id).await?;
                 }
+                IssuesCommands::Events { args } => {
+                    events::list_events(&client, args).await?;
+                }
                 IssuesCommands::Resolve {
                     issue_ids,
                     in_release,
@@ -108,16 +211,351 @@ async fn run() -> error::Result<()> {
                 }
             }
         }
+        Commands::Events { command } => {
+            let client = create_client()?;
+
+            match command {
+                EventsCommands::List { args } => {
+                    events::list_events(&client, args).await?;
+                }
+                EventsCommands::View {
+                    issue_id,
+                    event,
+                    in_app,
+                    breadcrumbs,
+                } => {
+                    let options = events::ViewOptions {
+                        in_app,
+                        breadcrumbs,
+                    };
+                    events::view_event(&client, &issue_id, &event, options).await?;
+                }
+            }
+        }
+        Commands::Releases { command } => {
+            let client = create_client()?;
+
+            match command {
+                ReleasesCommands::New {
+                    version,
+                    project,
+                    url,
+                    git_ref,
+                    finalize,
+                } => {
+                    let options = releases::NewOptions {
+                        projects: require_projects(project)?,
+                        url,
+                        git_ref,
+                        finalize,
+                    };
+                    releases::new_release(&client, &version, options).await?;
+                }
+                ReleasesCommands::Finalize { version, released } => {
+                    releases::finalize_release(&client, &version, released.as_deref()).await?;
+                }
+                ReleasesCommands::List {
+                    project,
+                    all_projects,
+                    query,
+                    limit,
+                } => {
+                    let project = if all_projects {
+                        None
+                    } else {
+                        get_project(project)?
+                    };
+                    releases::list_releases(&client, project, query, limit).await?;
+                }
+                ReleasesCommands::Info { version } => {
+                    releases::view_release(&client, &version).await?;
+                }
+                ReleasesCommands::SetCommits {
+                    version,
+                    auto,
+                    commit,
+                    clear,
+                    remote,
+                    initial_depth,
+                } => {
+                    let mode = if auto {
+                        releases::CommitsMode::Auto {
+                            remote,
+                            initial_depth,
+                        }
+                    } else if clear {
+                        releases::CommitsMode::Clear
+                    } else {
+                        releases::CommitsMode::Refs(commit)
+                    };
+                    releases::set_commits(&client, &version, mode).await?;
+                }
+                ReleasesCommands::Delete { version, confirm } => {
+                    releases::delete_release(&client, &version, confirm).await?;
+                }
+                ReleasesCommands::Deploys { version, command } => match command {
+                    DeploysCommands::New {
+                        env,
+                        name,
+                        url,
+                        started,
+                        finished,
+                        project,
+                    } => {
+                        let options = releases::NewDeployOptions {
+                            environment: env,
+                            name,
+                            url,
+                            started,
+                            finished,
+                            projects: project,
+                        };
+                        releases::new_deploy(&client, &version, options).await?;
+                    }
+                    DeploysCommands::List => {
+                        releases::list_deploys(&client, &version).await?;
+                    }
+                },
+            }
+        }
+        Commands::Sourcemaps { command } => match command {
+            SourcemapsCommands::Inject { paths, dry_run } => {
+                sourcemaps::inject(&paths, dry_run)?;
+            }
+            SourcemapsCommands::Upload {
+                paths,
+                project,
+                release,
+                dist,
+                url_prefix,
+                wait,
+                wait_timeout,
+            } => {
+                let client = create_client()?;
+                let options = sourcemaps::UploadOptions {
+                    projects: require_projects(project)?,
+                    release,
+                    dist,
+                    url_prefix,
+                    wait,
+                    wait_timeout,
+                };
+                sourcemaps::upload(&client, &paths, options).await?;
+            }
+        },
+        Commands::Projects { command } => {
+            let client = create_client()?;
+            match command {
+                ProjectsCommands::List => {
+                    projects::list_projects(&client).await?;
+                }
+                ProjectsCommands::View { project } => {
+                    projects::view_project(&client, &project).await?;
+                }
+                ProjectsCommands::Create {
+                    name,
+                    team,
+                    slug,
+                    platform,
+                } => {
+                    let options = projects::CreateOptions {
+                        team,
+                        slug,
+                        platform,
+                    };
+                    projects::create_project(&client, &name, options).await?;
+                }
+                ProjectsCommands::Delete { project, confirm } => {
+                    projects::delete_project(&client, &project, confirm).await?;
+                }
+                ProjectsCommands::Keys { command } => match command {
+                    ProjectKeysCommands::List { project } => {
+                        projects::list_keys(&client, &project).await?;
+                    }
+                    ProjectKeysCommands::Create { project, name } => {
+                        projects::create_key(&client, &project, name).await?;
+                    }
+                },
+            }
+        }
+        Commands::Orgs { command } => {
+            let client = create_client()?;
+            match command {
+                OrgsCommands::List => {
+                    orgs::list_orgs(&client).await?;
+                }
+                OrgsCommands::View { org } => {
+                    orgs::view_org(&client, org.as_deref()).await?;
+                }
+            }
+        }
+        Commands::Teams { command } => {
+            let client = create_client()?;
+            match command {
+                TeamsCommands::List => {
+                    teams::list_teams(&client).await?;
+                }
+                TeamsCommands::Create { slug, name } => {
+                    teams::create_team(&client, &slug, name).await?;
+                }
+                TeamsCommands::Members { team } => {
+                    teams::list_members(&client, &team).await?;
+                }
+            }
+        }
+        Commands::DebugFiles { command } => match command {
+            DebugFilesCommands::Check { path } => {
+                debug_files::check(&path)?;
+            }
+            DebugFilesCommands::Find { ids, path } => {
+                debug_files::find(&ids, &path)?;
+            }
+            DebugFilesCommands::Upload {
+                paths,
+                project,
+                wait,
+                wait_timeout,
+            } => {
+                let client = create_client()?;
+                let project = load_profile()?.require_project(project.as_deref())?;
+                let wait = wait.then_some(wait_timeout);
+                debug_files::upload(&client, &paths, &project, wait).await?;
+            }
+        },
+        Commands::Monitors { command } => {
+            let client = create_client()?;
+            match command {
+                MonitorsCommands::List => {
+                    monitors::list_monitors(&client).await?;
+                }
+                MonitorsCommands::View { monitor, limit } => {
+                    monitors::view_monitor(&client, &monitor, limit).await?;
+                }
+                MonitorsCommands::Run {
+                    monitor,
+                    environment,
+                    config,
+                    command,
+                } => {
+                    let options = monitors::RunOptions {
+                        environment,
+                        config,
+                        command,
+                    };
+                    let exit_code = monitors::run(&client, &monitor, options).await?;
+                    if exit_code != 0 {
+                        std::process::exit(exit_code);
+                    }
+                }
+                MonitorsCommands::Checkin {
+                    monitor,
+                    status,
+                    id,
+                    duration,
+                    environment,
+                    config,
+                } => {
+                    let options = monitors::CheckinOptions {
+                        status,
+                        id,
+                        duration,
+                        environment,
+                        config,
+                    };
+                    monitors::checkin(&client, &monitor, options).await?;
+                }
+            }
+        }
+        Commands::SendEvent { args } => {
+            let client = create_ingest_client(args.dsn.as_deref())?;
+            send::send_event(&client, args).await?;
+        }
+        Commands::SendEnvelope { path, dsn } => {
+            let client = create_ingest_client(dsn.as_deref())?;
+            send::send_envelope(&client, &path).await?;
+        }
+        Commands::Login {
+            device,
+            client_id,
+            no_browser,
+        } => {
+            let options = auth::LoginOptions {
+                server: cli.server.clone(),
+                token: cli.token.clone(),
+                device,
+                client_id,
+                no_browser,
+                verbose: cli.verbose,
+            };
+            auth::login(profile, options).await?;
+        }
+        Commands::Logout => {
+            auth::logout(profile)?;
+        }
+        Commands::Auth { command } => match command {
+            AuthCommands::Status => {
+                let raw = load_config()?;
+                let name = raw.get_profile(profile);
+                let config = raw.with_profile(profile)?.with_local_config()?;
+                let (token, source) = config.resolve_auth_token(cli.token.as_deref())?;
+                let client = api::SentryClient::new(
+                    &config,
+                    cli.org.as_deref(),
+                    cli.server.as_deref(),
+                    Some(&token),
+                    cli.max_retries,
+                    cli.verbose,
+                )?;
+                let name = name.as_deref().unwrap_or(config::DEFAULT_PROFILE);
+                auth::status(&client, name, &source).await?;
+            }
+        },
         Commands::Config { command } => match command {
             ConfigCommands::Init => {
-                config_cmd::init_config()?;
+                config_cmd::init_config(profile).await?;
             }
             ConfigCommands::Show => {
-                config_cmd::show_config()?;
+                config_cmd::show_config(profile)?;
             }
             ConfigCommands::Set { key, value } => {
-                config_cmd::set_config(&key, &value)?;
+                config_cmd::set_config(&key, &value, profile)?;
+            }
+            ConfigCommands::Get { key } => {
+                config_cmd::get_config(&key, profile)?;
+            }
+            ConfigCommands::Unset { key } => {
+                config_cmd::unset_config(&key, profile)?;
+            }
+            ConfigCommands::List { json } => {
+                config_cmd::list_config(profile, json)?;
             }
+            ConfigCommands::Profiles { command } => match command {
+                ProfilesCommands::List => {
+                    config_cmd::list_profiles(profile)?;
+                }
+                ProfilesCommands::Use { name } => {
+                    config_cmd::use_profile(&name)?;
+                }
+                ProfilesCommands::Add {
+                    name,
+                    server_url,
+                    default_org,
+                    default_project,
+                    auth_token,
+                } => {
+                    let settings = config::Settings {
+                        server_url,
+                        default_org,
+                        default_project,
+                        auth_token,
+                        ..Default::default()
+                    };
+                    config_cmd::add_profile(&name, settings)?;
+                }
+                ProfilesCommands::Remove { name } => {
+                    config_cmd::remove_profile(&name)?;
+                }
+            },
         },
         Commands::Completions { shell } => {
             let mut cmd = Cli::command();
@@ -0,0 +1,158 @@
+use super::{get_format, json, template};
+use crate::cli::args::OutputFormat;
+use crate::error::Result;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{self, Write};
+
+/// Print any serializable value in the selected machine-readable format.
+///
+/// JSON and YAML print the value as a document. The line formats print one
+/// record per item of a list, or a single record for anything else: NDJSON
+/// as compact JSON, CSV, TSV and Markdown as rows whose columns are the
+/// fields of the items. A template given by `--template` is rendered per
+/// record in the same way, whatever the format. A `--jq` filter is applied
+/// to the JSON document instead.
+pub fn print_data<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    if json::is_jq_set() {
+        return super::print_json(value);
+    }
+    if template::is_set() {
+        return template::print_records(&records(value));
+    }
+    match get_format() {
+        OutputFormat::Yaml => print!("{}", to_yaml(value)),
+        OutputFormat::Json | OutputFormat::Table | OutputFormat::Compact => {
+            return super::print_json(value)
+        }
+        format => RecordWriter::new(format).write(&records(value)),
+    }
+    Ok(())
+}
+
+/// The items of a list, or the value itself if it is not one
+fn records<T: Serialize + ?Sized>(value: &T) -> Vec<Value> {
+    match serde_json::to_value(value).unwrap_or(Value::Null) {
+        Value::Array(items) => items,
+        value => vec![value],
+    }
+}
+
+pub(super) fn to_yaml<T: Serialize + ?Sized>(value: &T) -> String {
+    serde_yaml::to_string(value).unwrap_or_else(|_| "null\n".to_string())
+}
+
+/// Writes records in one of the line formats. The columns are taken from
+/// the records of the first `write`, so that records written later, such as
+/// further pages of a list, line up with the header.
+pub(super) struct RecordWriter {
+    format: OutputFormat,
+    columns: Option<Vec<String>>,
+}
+
+impl RecordWriter {
+    pub(super) fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            columns: None,
+        }
+    }
+
+    pub(super) fn write(&mut self, records: &[Value]) {
+        let mut out = io::stdout().lock();
+
+        if self.format == OutputFormat::Ndjson {
+            for record in records {
+                let _ = writeln!(out, "{}", record);
+            }
+            let _ = out.flush();
+            return;
+        }
+
+        if records.is_empty() {
+            return;
+        }
+        if self.columns.is_none() {
+            let columns = columns(records);
+            let _ = writeln!(out, "{}", self.line(&columns));
+            if self.format == OutputFormat::Markdown {
+                let rule = vec!["---".to_string(); columns.len()];
+                let _ = writeln!(out, "{}", self.line(&rule));
+            }
+            self.columns = Some(columns);
+        }
+
+        let columns = self.columns.as_deref().unwrap_or_default();
+        for record in records {
+            let cells: Vec<String> = match record {
+                Value::Object(fields) => columns.iter().map(|c| cell(fields.get(c))).collect(),
+                value => vec![cell(Some(value))],
+            };
+            let _ = writeln!(out, "{}", self.line(&cells));
+        }
+        let _ = out.flush();
+    }
+
+    fn line(&self, cells: &[String]) -> String {
+        match self.format {
+            OutputFormat::Tsv => cells
+                .iter()
+                .map(|c| escape_tsv(c))
+                .collect::<Vec<_>>()
+                .join("\t"),
+            OutputFormat::Markdown => {
+                let cells: Vec<String> = cells.iter().map(|c| escape_markdown(c)).collect();
+                format!("| {} |", cells.join(" | "))
+            }
+            _ => {
+                let mut writer = csv::WriterBuilder::new()
+                    .terminator(csv::Terminator::Any(b'\n'))
+                    .from_writer(Vec::new());
+                let _ = writer.write_record(cells);
+                let bytes = writer.into_inner().unwrap_or_default();
+                String::from_utf8_lossy(&bytes).trim_end().to_string()
+            }
+        }
+    }
+}
+
+/// Field names of the records in order of first appearance, or a single
+/// `value` column if the records are not objects
+fn columns(records: &[Value]) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for record in records {
+        match record {
+            Value::Object(fields) => {
+                for key in fields.keys() {
+                    if !columns.contains(key) {
+                        columns.push(key.clone());
+                    }
+                }
+            }
+            _ => return vec!["value".to_string()],
+        }
+    }
+    columns
+}
+
+/// Text of a field: strings as they are, nested objects and lists as JSON
+fn cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn escape_tsv(cell: &str) -> String {
+    cell.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn escape_markdown(cell: &str) -> String {
+    cell.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
@@ -0,0 +1,371 @@
+use super::table::{format_relative_time, format_status_colored, truncate_string};
+use super::{get_format, json, template};
+use crate::api::models::Issue;
+use crate::cli::args::OutputFormat;
+use crate::error::{Result, SentryCliError};
+use serde_json::{Map, Value};
+use std::io::{self, IsTerminal};
+use std::str::FromStr;
+
+/// A column of the issues table, named after the field of `Issue` it shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueField {
+    Id,
+    ShortId,
+    Title,
+    Status,
+    Level,
+    Count,
+    UserCount,
+    FirstSeen,
+    LastSeen,
+    Permalink,
+    Project,
+    AssignedTo,
+    IsBookmarked,
+    IsSubscribed,
+    HasSeen,
+    Metadata,
+    Culprit,
+}
+
+const ALL_FIELDS: &[IssueField] = &[
+    IssueField::Id,
+    IssueField::ShortId,
+    IssueField::Title,
+    IssueField::Status,
+    IssueField::Level,
+    IssueField::Count,
+    IssueField::UserCount,
+    IssueField::FirstSeen,
+    IssueField::LastSeen,
+    IssueField::Permalink,
+    IssueField::Project,
+    IssueField::AssignedTo,
+    IssueField::IsBookmarked,
+    IssueField::IsSubscribed,
+    IssueField::HasSeen,
+    IssueField::Metadata,
+    IssueField::Culprit,
+];
+
+/// Columns shown when neither `--fields` nor `issue_fields` is set
+const DEFAULT_FIELDS: &[IssueField] = &[
+    IssueField::Id,
+    IssueField::ShortId,
+    IssueField::Title,
+    IssueField::Status,
+    IssueField::Count,
+    IssueField::LastSeen,
+];
+
+impl IssueField {
+    fn name(self) -> &'static str {
+        match self {
+            IssueField::Id => "id",
+            IssueField::ShortId => "short_id",
+            IssueField::Title => "title",
+            IssueField::Status => "status",
+            IssueField::Level => "level",
+            IssueField::Count => "count",
+            IssueField::UserCount => "user_count",
+            IssueField::FirstSeen => "first_seen",
+            IssueField::LastSeen => "last_seen",
+            IssueField::Permalink => "permalink",
+            IssueField::Project => "project",
+            IssueField::AssignedTo => "assigned_to",
+            IssueField::IsBookmarked => "is_bookmarked",
+            IssueField::IsSubscribed => "is_subscribed",
+            IssueField::HasSeen => "has_seen",
+            IssueField::Metadata => "metadata",
+            IssueField::Culprit => "culprit",
+        }
+    }
+
+    /// Other names accepted for the field, matching the table headers
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            IssueField::Count => &["events"],
+            IssueField::UserCount => &["users"],
+            IssueField::Permalink => &["link", "url"],
+            IssueField::AssignedTo => &["assignee"],
+            IssueField::IsBookmarked => &["bookmarked"],
+            IssueField::IsSubscribed => &["subscribed"],
+            IssueField::HasSeen => &["seen"],
+            _ => &[],
+        }
+    }
+
+    fn header(self) -> &'static str {
+        match self {
+            IssueField::Id => "ID",
+            IssueField::ShortId => "Short ID",
+            IssueField::Title => "Title",
+            IssueField::Status => "Status",
+            IssueField::Level => "Level",
+            IssueField::Count => "Events",
+            IssueField::UserCount => "Users",
+            IssueField::FirstSeen => "First Seen",
+            IssueField::LastSeen => "Last Seen",
+            IssueField::Permalink => "Link",
+            IssueField::Project => "Project",
+            IssueField::AssignedTo => "Assignee",
+            IssueField::IsBookmarked => "Bookmarked",
+            IssueField::IsSubscribed => "Subscribed",
+            IssueField::HasSeen => "Seen",
+            IssueField::Metadata => "Metadata",
+            IssueField::Culprit => "Culprit",
+        }
+    }
+
+    /// Column width in tables printed page by page, which cannot size their
+    /// columns to the data
+    fn width(self) -> usize {
+        match self {
+            IssueField::Id => 10,
+            IssueField::ShortId => 18,
+            IssueField::Title | IssueField::Permalink => 50,
+            IssueField::Metadata | IssueField::Culprit => 40,
+            IssueField::Project | IssueField::AssignedTo => 20,
+            IssueField::Status | IssueField::FirstSeen | IssueField::LastSeen => 12,
+            IssueField::IsBookmarked | IssueField::IsSubscribed => 10,
+            IssueField::Level | IssueField::Count | IssueField::UserCount => 7,
+            IssueField::HasSeen => 4,
+        }
+    }
+
+    /// Longest text shown when the table is not fitted to a terminal
+    fn max_len(self) -> Option<usize> {
+        match self {
+            IssueField::Title => Some(50),
+            IssueField::Metadata | IssueField::Culprit => Some(40),
+            _ => None,
+        }
+    }
+
+    fn value(self, issue: &Issue) -> String {
+        match self {
+            IssueField::Id => issue.id.clone(),
+            IssueField::ShortId => issue.short_id.clone(),
+            IssueField::Title => issue.title.clone(),
+            IssueField::Status => format_status_colored(&issue.status),
+            IssueField::Level => issue.level.clone(),
+            IssueField::Count => issue.count.clone(),
+            IssueField::UserCount => issue.user_count.to_string(),
+            IssueField::FirstSeen => format_relative_time(&issue.first_seen),
+            IssueField::LastSeen => format_relative_time(&issue.last_seen),
+            IssueField::Permalink => issue.permalink.clone(),
+            IssueField::Project => issue.project.slug.clone(),
+            IssueField::AssignedTo => issue
+                .assigned_to
+                .as_ref()
+                .map(|a| a.name.clone())
+                .unwrap_or_default(),
+            IssueField::IsBookmarked => yes_no(issue.is_bookmarked),
+            IssueField::IsSubscribed => yes_no(issue.is_subscribed),
+            IssueField::HasSeen => yes_no(issue.has_seen),
+            IssueField::Metadata => {
+                let metadata = &issue.metadata;
+                metadata
+                    .value
+                    .as_ref()
+                    .or(metadata.function.as_ref())
+                    .or(metadata.filename.as_ref())
+                    .cloned()
+                    .unwrap_or_default()
+            }
+            IssueField::Culprit => issue.culprit.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Value of the field in CSV, TSV and Markdown output: that of the JSON
+    /// output, except for nested fields, which get the text of the table
+    fn record_value(self, issue: &Issue, json: &Map<String, Value>, key: &str) -> Value {
+        match self {
+            IssueField::Project | IssueField::AssignedTo | IssueField::Metadata => {
+                Value::from(self.value(issue))
+            }
+            _ => json.get(key).cloned().unwrap_or(Value::Null),
+        }
+    }
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.to_string()
+}
+
+/// Field names compare without case, `_` or `-`, so that the JSON names
+/// (`shortId`) work as well
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+impl FromStr for IssueField {
+    type Err = SentryCliError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let wanted = normalize(s.trim());
+        ALL_FIELDS
+            .iter()
+            .copied()
+            .find(|field| {
+                normalize(field.name()) == wanted
+                    || field
+                        .aliases()
+                        .iter()
+                        .any(|alias| normalize(alias) == wanted)
+            })
+            .ok_or_else(|| {
+                let names: Vec<&str> = ALL_FIELDS.iter().map(|f| f.name()).collect();
+                SentryCliError::Validation(format!(
+                    "Unknown issue field: {}. Valid fields: {}",
+                    s.trim(),
+                    names.join(", ")
+                ))
+            })
+    }
+}
+
+/// Parse a comma-separated list of issue fields
+pub fn parse_issue_fields(value: &str) -> Result<Vec<IssueField>> {
+    let fields = value
+        .split(',')
+        .filter(|name| !name.trim().is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<IssueField>>>()?;
+
+    if fields.is_empty() {
+        return Err(SentryCliError::Validation(
+            "No issue fields given. Expected a list such as id,short_id,title".to_string(),
+        ));
+    }
+    Ok(fields)
+}
+
+/// Which columns the issues table shows and how long their text may get
+#[derive(Debug, Clone)]
+pub struct IssueColumns {
+    fields: Vec<IssueField>,
+    truncate: bool,
+    /// Whether the fields were chosen by `--fields` or `issue_fields`
+    selected: bool,
+}
+
+impl Default for IssueColumns {
+    fn default() -> Self {
+        Self {
+            fields: DEFAULT_FIELDS.to_vec(),
+            truncate: true,
+            selected: false,
+        }
+    }
+}
+
+impl IssueColumns {
+    /// Columns from a comma-separated list of fields, or the default ones.
+    /// Without `truncate`, text is shown in full even if the table gets
+    /// wider than the terminal.
+    pub fn new(fields: Option<&str>, truncate: bool) -> Result<Self> {
+        let selected = fields.is_some();
+        let fields = match fields {
+            Some(fields) => parse_issue_fields(fields)?,
+            None => DEFAULT_FIELDS.to_vec(),
+        };
+        Ok(Self {
+            fields,
+            truncate,
+            selected,
+        })
+    }
+
+    /// The issues as records of only the selected fields, named as in the
+    /// JSON output, if fields were selected and the output is CSV, TSV or
+    /// Markdown. The other data formats print whole issues.
+    pub fn records(&self, issues: &[Issue]) -> Option<Vec<Value>> {
+        let tabular = matches!(
+            get_format(),
+            OutputFormat::Csv | OutputFormat::Tsv | OutputFormat::Markdown
+        );
+        if !self.selected || !tabular || json::is_jq_set() || template::is_set() {
+            return None;
+        }
+
+        let records = issues
+            .iter()
+            .map(|issue| {
+                let json = match serde_json::to_value(issue) {
+                    Ok(Value::Object(fields)) => fields,
+                    _ => Map::new(),
+                };
+                let mut record = Map::new();
+                for field in &self.fields {
+                    let key = json
+                        .keys()
+                        .find(|key| normalize(key) == normalize(field.name()))
+                        .cloned()
+                        .unwrap_or_else(|| field.name().to_string());
+                    let value = field.record_value(issue, &json, &key);
+                    record.insert(key, value);
+                }
+                Value::Object(record)
+            })
+            .collect();
+        Some(records)
+    }
+
+    pub(super) fn header(&self) -> Vec<String> {
+        self.fields.iter().map(|f| f.header().to_string()).collect()
+    }
+
+    pub(super) fn record(&self, issue: &Issue) -> Vec<String> {
+        // Long text is cut to fixed lengths only if the table will not be
+        // fitted to the terminal instead
+        let cut = self.truncate && terminal_width().is_none();
+        self.fields
+            .iter()
+            .map(|field| {
+                let value = field.value(issue);
+                match field.max_len() {
+                    Some(max) if cut => truncate_string(&value, max),
+                    _ => value,
+                }
+            })
+            .collect()
+    }
+
+    pub(super) fn widths(&self) -> Vec<usize> {
+        self.fields.iter().map(|f| f.width()).collect()
+    }
+
+    pub(super) fn position(&self, field: IssueField) -> Option<usize> {
+        self.fields.iter().position(|f| *f == field)
+    }
+
+    pub(super) fn truncate(&self) -> bool {
+        self.truncate
+    }
+
+    /// Width to fit the table into: that of the terminal, unless text should
+    /// not be truncated
+    pub(super) fn max_width(&self) -> Option<usize> {
+        if self.truncate {
+            terminal_width()
+        } else {
+            None
+        }
+    }
+}
+
+/// Width of the terminal, if output goes to one
+fn terminal_width() -> Option<usize> {
+    if !io::stdout().is_terminal() {
+        return None;
+    }
+    crossterm::terminal::size()
+        .ok()
+        .map(|(width, _)| usize::from(width))
+        .filter(|width| *width > 0)
+}
@@ -1,11 +1,104 @@
-use crate::api::models::Issue;
+use crate::error::{Result, SentryCliError};
+use jaq_core::load::{Arena, File, Loader};
+use jaq_core::{Compiler, Ctx, Filter, Native, RcIter};
+use jaq_json::Val;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::OnceLock;
 
-pub fn print_issues_json(issues: &[Issue]) {
-    let json = serde_json::to_string_pretty(issues).unwrap_or_else(|_| "[]".to_string());
-    println!("{}", json);
+/// Expression given by `--jq`, if any
+static JQ: OnceLock<String> = OnceLock::new();
+
+/// Set the jq expression that JSON output is filtered through for the rest
+/// of the run. The expression is checked here, before any request is made.
+pub fn set_jq(expr: &str) -> Result<()> {
+    compile(expr)?;
+    let _ = JQ.set(expr.to_string());
+    Ok(())
+}
+
+pub(super) fn is_jq_set() -> bool {
+    JQ.get().is_some()
+}
+
+/// Print any serializable value as pretty JSON, or the results of `--jq`
+/// applied to it: strings as plain text, anything else as JSON
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    let Some(expr) = JQ.get() else {
+        let json = serde_json::to_string_pretty(value).unwrap_or_else(|_| "null".to_string());
+        println!("{}", json);
+        return Ok(());
+    };
+
+    let filter = compile(expr)?;
+    let input = serde_json::to_value(value).unwrap_or(Value::Null);
+    let inputs = RcIter::new(core::iter::empty());
+    for result in filter.run((Ctx::new([], &inputs), Val::from(input))) {
+        let value = result
+            .map(Value::from)
+            .map_err(|e| SentryCliError::Validation(format!("jq filter failed: {}", e)))?;
+        match value {
+            Value::String(s) => println!("{}", s),
+            value => println!(
+                "{}",
+                serde_json::to_string_pretty(&value).unwrap_or_else(|_| "null".to_string())
+            ),
+        }
+    }
+    Ok(())
 }
 
-pub fn print_issue_json(issue: &Issue) {
-    let json = serde_json::to_string_pretty(issue).unwrap_or_else(|_| "{}".to_string());
-    println!("{}", json);
+fn compile(expr: &str) -> Result<Filter<Native<Val>>> {
+    let invalid = |reason: String| {
+        SentryCliError::Validation(format!("Invalid jq expression '{}': {}", expr, reason))
+    };
+    // Position of the text `rest` within the expression, counted from 1
+    let position = |rest: &str| expr.len() - rest.len() + 1;
+
+    let loader = Loader::new(jaq_std::defs().chain(jaq_json::defs()));
+    let arena = Arena::default();
+    let modules = loader
+        .load(
+            &arena,
+            File {
+                code: expr,
+                path: (),
+            },
+        )
+        .map_err(|errors| {
+            let reasons: Vec<String> = errors
+                .into_iter()
+                .flat_map(|(_, error)| match error {
+                    jaq_core::load::Error::Io(errors) => errors
+                        .into_iter()
+                        .map(|(path, e)| format!("cannot load {}: {}", path, e))
+                        .collect::<Vec<_>>(),
+                    jaq_core::load::Error::Lex(errors) => errors
+                        .into_iter()
+                        .map(|(expect, rest)| {
+                            format!("expected {} at {}", expect.as_str(), position(rest))
+                        })
+                        .collect(),
+                    jaq_core::load::Error::Parse(errors) => errors
+                        .into_iter()
+                        .map(|(expect, rest)| {
+                            format!("expected {} at {}", expect.as_str(), position(rest))
+                        })
+                        .collect(),
+                })
+                .collect();
+            invalid(reasons.join(", "))
+        })?;
+
+    Compiler::default()
+        .with_funs(jaq_std::funs().chain(jaq_json::funs()))
+        .compile(modules)
+        .map_err(|errors| {
+            let reasons: Vec<String> = errors
+                .into_iter()
+                .flat_map(|(_, errors)| errors)
+                .map(|(name, undefined)| format!("undefined {} {}", undefined.as_str(), name))
+                .collect();
+            invalid(reasons.join(", "))
+        })
 }
@@ -1,15 +1,28 @@
+mod data;
+mod fields;
 mod json;
+mod progress;
+mod stream;
 mod table;
+mod template;
 
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 
+use crate::api::Cursors;
 use crate::cli::args::OutputFormat;
+use crate::error::Result;
 
+pub use data::print_data;
+pub use fields::{parse_issue_fields, IssueColumns};
 pub use json::*;
+pub use progress::Progress;
+pub use stream::DataStream;
 pub use table::*;
+pub use template::set_template;
 
 /// Global output format setting (thread-safe)
-/// 0 = Table, 1 = Json, 2 = Compact
+/// 0 = Table, 1 = Json, 2 = Compact, 3 = Csv, 4 = Tsv, 5 = Ndjson, 6 = Yaml,
+/// 7 = Markdown
 static OUTPUT_FORMAT: AtomicU8 = AtomicU8::new(0);
 static QUIET_MODE: AtomicBool = AtomicBool::new(false);
 
@@ -18,6 +31,11 @@ pub fn set_format(format: OutputFormat) {
         OutputFormat::Table => 0,
         OutputFormat::Json => 1,
         OutputFormat::Compact => 2,
+        OutputFormat::Csv => 3,
+        OutputFormat::Tsv => 4,
+        OutputFormat::Ndjson => 5,
+        OutputFormat::Yaml => 6,
+        OutputFormat::Markdown => 7,
     };
     OUTPUT_FORMAT.store(value, Ordering::Relaxed);
 }
@@ -26,6 +44,11 @@ pub fn get_format() -> OutputFormat {
     match OUTPUT_FORMAT.load(Ordering::Relaxed) {
         1 => OutputFormat::Json,
         2 => OutputFormat::Compact,
+        3 => OutputFormat::Csv,
+        4 => OutputFormat::Tsv,
+        5 => OutputFormat::Ndjson,
+        6 => OutputFormat::Yaml,
+        7 => OutputFormat::Markdown,
         _ => OutputFormat::Table,
     }
 }
@@ -38,18 +61,38 @@ pub fn is_quiet() -> bool {
     QUIET_MODE.load(Ordering::Relaxed)
 }
 
-pub fn is_json_output() -> bool {
-    matches!(get_format(), OutputFormat::Json)
+/// Whether output is for other programs rather than people: a jq filter, a
+/// template, or any format but table and compact
+pub fn is_data_output() -> bool {
+    json::is_jq_set()
+        || template::is_set()
+        || !matches!(get_format(), OutputFormat::Table | OutputFormat::Compact)
+}
+
+pub fn is_compact() -> bool {
+    matches!(get_format(), OutputFormat::Compact)
 }
 
 /// Print a success message (respects quiet mode)
-pub fn print_message(message: &str) {
+pub fn print_message(message: &str) -> Result<()> {
     if is_quiet() {
-        return;
+        return Ok(());
     }
-    if is_json_output() {
-        println!(r#"{{"message": "{}"}}"#, message.replace('"', "\\\""));
+    if is_data_output() {
+        print_data(&serde_json::json!({ "message": message }))
     } else {
         println!("{message}");
+        Ok(())
+    }
+}
+
+/// Print the cursors of the pages around a listing to stderr, so that they
+/// stay out of piped table output
+pub fn print_cursors(cursors: &Cursors) {
+    if let Some(next) = &cursors.next {
+        eprintln!("Next page: --cursor {}", next);
+    }
+    if let Some(prev) = &cursors.prev {
+        eprintln!("Previous page: --cursor {}", prev);
     }
 }
@@ -0,0 +1,43 @@
+use std::io::{self, IsTerminal, Write};
+
+/// A single-line progress counter on stderr.
+///
+/// Only drawn when stderr is a terminal and quiet mode is off, so piped or
+/// scripted runs are not cluttered with carriage returns.
+pub struct Progress {
+    label: String,
+    total: usize,
+    current: usize,
+    enabled: bool,
+}
+
+impl Progress {
+    pub fn new(label: &str, total: usize) -> Self {
+        let progress = Self {
+            label: label.to_string(),
+            total,
+            current: 0,
+            enabled: !super::is_quiet() && io::stderr().is_terminal(),
+        };
+        progress.draw();
+        progress
+    }
+
+    pub fn inc(&mut self, amount: usize) {
+        self.current = (self.current + amount).min(self.total);
+        self.draw();
+    }
+
+    pub fn finish(self) {
+        if self.enabled {
+            eprintln!();
+        }
+    }
+
+    fn draw(&self) {
+        if self.enabled {
+            eprint!("\r{} {}/{}", self.label, self.current, self.total);
+            let _ = io::stderr().flush();
+        }
+    }
+}
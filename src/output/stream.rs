@@ -0,0 +1,290 @@
+use super::data::{to_yaml, RecordWriter};
+use super::table::{compact_table, fit_width, print_compact, rounded_table};
+use super::{get_format, is_compact, json, template};
+use crate::cli::args::OutputFormat;
+use crate::error::Result;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{self, Write};
+use tabled::settings::object::Columns;
+use tabled::settings::Width;
+use tabled::Tabled;
+
+/// Prints a list one page of items at a time in the selected machine-readable
+/// format, producing the same text as `print_data` would for the whole list.
+///
+/// In JSON and YAML the list is either the whole document or the first field
+/// of an object, whose other fields are only known once all items are
+/// printed. The line formats and templates only print the items.
+///
+/// A `--jq` filter needs the whole document, so the items are kept until
+/// `finish` and filtered together with the fields around them, as the JSON
+/// document is printed.
+pub struct DataStream {
+    format: OutputFormat,
+    template: bool,
+    filtered: Option<Vec<Value>>,
+    field: Option<String>,
+    count: usize,
+    records: RecordWriter,
+}
+
+impl DataStream {
+    pub fn new() -> Self {
+        Self::start(None)
+    }
+
+    /// Start an object whose field `key` is the streamed list
+    pub fn field(key: &str) -> Self {
+        Self::start(Some(key.to_string()))
+    }
+
+    fn start(field: Option<String>) -> Self {
+        let format = get_format();
+        let template = template::is_set();
+        let filtered = json::is_jq_set().then(Vec::new);
+        if format == OutputFormat::Json && !template && filtered.is_none() {
+            match &field {
+                Some(key) => print!("{{\n  {}: [", Value::from(key.as_str())),
+                None => print!("["),
+            }
+        }
+        Self {
+            format,
+            template,
+            filtered,
+            field,
+            count: 0,
+            records: RecordWriter::new(format),
+        }
+    }
+
+    /// Whether fields besides the list are part of the output, which is
+    /// only the case for JSON and YAML, also when filtered by `--jq`
+    pub fn has_fields(&self) -> bool {
+        self.filtered.is_some()
+            || (!self.template && matches!(self.format, OutputFormat::Json | OutputFormat::Yaml))
+    }
+
+    pub fn push<T: Serialize>(&mut self, items: &[T]) -> Result<()> {
+        let records = || -> Vec<Value> {
+            items
+                .iter()
+                .map(|item| serde_json::to_value(item).unwrap_or(Value::Null))
+                .collect()
+        };
+        if let Some(filtered) = &mut self.filtered {
+            filtered.extend(records());
+            self.count += items.len();
+            return Ok(());
+        }
+        match self.format {
+            _ if self.template => template::print_records(&records())?,
+            OutputFormat::Json => self.push_json(items),
+            OutputFormat::Yaml => self.push_yaml(items),
+            _ => self.records.write(&records()),
+        }
+        self.count += items.len();
+        Ok(())
+    }
+
+    fn push_json<T: Serialize>(&mut self, items: &[T]) {
+        let mut out = io::stdout().lock();
+        let indent = if self.field.is_some() { "    " } else { "  " };
+        for (i, item) in items.iter().enumerate() {
+            let json = serde_json::to_string_pretty(item).unwrap_or_else(|_| "null".to_string());
+            let separator = if self.count + i == 0 { "" } else { "," };
+            let _ = write!(
+                out,
+                "{}\n{}{}",
+                separator,
+                indent,
+                json.replace('\n', &format!("\n{}", indent))
+            );
+        }
+        let _ = out.flush();
+    }
+
+    fn push_yaml<T: Serialize>(&mut self, items: &[T]) {
+        if items.is_empty() {
+            return;
+        }
+        let mut out = io::stdout().lock();
+        if self.count == 0 {
+            if let Some(key) = &self.field {
+                let _ = writeln!(out, "{}:", key);
+            }
+        }
+        // A list of one item renders as a single `- ` entry; the entries
+        // together form the whole list, also when nested in a field
+        for item in items {
+            let _ = write!(out, "{}", to_yaml(&[item]));
+        }
+        let _ = out.flush();
+    }
+
+    pub fn finish(self) -> Result<()> {
+        self.finish_with::<()>(&[])
+    }
+
+    /// Close the list and the object started by `field`, adding `fields`
+    /// after the list. The fields are dropped unless `has_fields` is true.
+    pub fn finish_with<T: Serialize>(self, fields: &[(&str, T)]) -> Result<()> {
+        if let Some(items) = self.filtered {
+            let document = match self.field {
+                Some(key) => {
+                    let mut object = serde_json::Map::new();
+                    object.insert(key, Value::Array(items));
+                    for (key, value) in fields {
+                        let value = serde_json::to_value(value).unwrap_or(Value::Null);
+                        object.insert(key.to_string(), value);
+                    }
+                    Value::Object(object)
+                }
+                None => Value::Array(items),
+            };
+            return super::print_json(&document);
+        }
+        if self.template {
+            return Ok(());
+        }
+        match self.format {
+            OutputFormat::Json => {
+                if self.count > 0 {
+                    print!("\n{}", if self.field.is_some() { "  " } else { "" });
+                }
+                print!("]");
+                if self.field.is_some() {
+                    for (key, value) in fields {
+                        let json = serde_json::to_string_pretty(value)
+                            .unwrap_or_else(|_| "null".to_string());
+                        print!(",\n  {}: {}", Value::from(*key), json.replace('\n', "\n  "));
+                    }
+                    print!("\n}}");
+                }
+                println!();
+            }
+            OutputFormat::Yaml => {
+                let mut rest = serde_json::Map::new();
+                if self.count == 0 {
+                    match &self.field {
+                        Some(key) => {
+                            rest.insert(key.clone(), Value::Array(Vec::new()));
+                        }
+                        None => print!("{}", to_yaml(&Value::Array(Vec::new()))),
+                    }
+                }
+                if self.field.is_some() {
+                    for (key, value) in fields {
+                        let value = serde_json::to_value(value).unwrap_or(Value::Null);
+                        rest.insert(key.to_string(), value);
+                    }
+                }
+                if !rest.is_empty() {
+                    print!("{}", to_yaml(&rest));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Prints a table in chunks as rows arrive. Columns have fixed widths so
+/// that the chunks line up; the header is printed with the first chunk and
+/// the bottom border by `finish`. In compact mode only the rows are printed.
+pub struct TableStream {
+    widths: Vec<usize>,
+    truncate: bool,
+    max_width: Option<usize>,
+    compact: bool,
+    bottom: Option<String>,
+    count: usize,
+}
+
+impl TableStream {
+    pub fn new(widths: &[usize]) -> Self {
+        Self {
+            widths: widths.to_vec(),
+            truncate: true,
+            max_width: None,
+            compact: is_compact(),
+            bottom: None,
+            count: 0,
+        }
+    }
+
+    /// Whether text longer than its column is cut. If not, the widths are
+    /// only a minimum and columns of later chunks may not line up.
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Cut the widest columns further to keep the table within `width`
+    pub fn max_width(mut self, width: Option<usize>) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    pub fn is_compact(&self) -> bool {
+        self.compact
+    }
+
+    pub fn push<R: Tabled>(&mut self, rows: Vec<R>) {
+        let header: Vec<String> = R::headers().into_iter().map(|h| h.into_owned()).collect();
+        let records = rows
+            .iter()
+            .map(|row| row.fields().into_iter().map(|f| f.into_owned()).collect())
+            .collect();
+        self.push_records(&header, records);
+    }
+
+    pub fn push_records(&mut self, header: &[String], records: Vec<Vec<String>>) {
+        if records.is_empty() {
+            return;
+        }
+        self.count += records.len();
+
+        let mut table = if self.compact {
+            compact_table(records)
+        } else {
+            rounded_table(header.to_vec(), records)
+        };
+        for (column, width) in self.widths.iter().enumerate() {
+            if self.truncate {
+                table.modify(Columns::one(column), Width::truncate(*width).suffix("..."));
+            }
+            table.modify(Columns::one(column), Width::increase(*width));
+        }
+        if let Some(width) = self.max_width {
+            fit_width(&mut table, width);
+        }
+
+        if self.compact {
+            print_compact(&table);
+            return;
+        }
+
+        let rendered = table.to_string();
+        let mut lines: Vec<&str> = rendered.lines().collect();
+        let bottom = lines.pop().unwrap_or_default().to_string();
+        // Later chunks continue the first one: drop their top border and header
+        let skip = if self.bottom.is_some() { 3 } else { 0 };
+
+        let mut out = io::stdout().lock();
+        for line in lines.iter().skip(skip) {
+            let _ = writeln!(out, "{}", line);
+        }
+        let _ = out.flush();
+        self.bottom = Some(bottom);
+    }
+
+    /// Close the table and return the number of rows printed
+    pub fn finish(self) -> usize {
+        if let Some(bottom) = self.bottom {
+            println!("{}", bottom);
+        }
+        self.count
+    }
+}
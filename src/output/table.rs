@@ -1,52 +1,106 @@
-use crate::api::models::Issue;
+use super::fields::{IssueColumns, IssueField};
+use super::is_compact;
+use super::stream::TableStream;
+use crate::api::models::{
+    AuthInfo, CheckIn, CheckInStatus, Deploy, Event, ExceptionValue, Frame, Issue, Monitor,
+    Organization, Project, ProjectKey, Release, Team, TeamMember,
+};
+use crate::config::{ConfigValue, Settings};
+use crate::upload::dif::{DifFile, UploadResult, UploadStatus};
 use chrono::{DateTime, Utc};
 use colored::Colorize;
-use tabled::settings::Style;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use tabled::builder::Builder;
+use tabled::settings::peaker::Priority;
+use tabled::settings::{Padding, Style, Width};
 use tabled::{Table, Tabled};
 
-#[derive(Tabled)]
-struct IssueRow {
-    #[tabled(rename = "ID")]
-    id: String,
-    #[tabled(rename = "Short ID")]
-    short_id: String,
-    #[tabled(rename = "Title")]
-    title: String,
-    #[tabled(rename = "Status")]
-    status: String,
-    #[tabled(rename = "Events")]
-    events: String,
-    #[tabled(rename = "Last Seen")]
-    last_seen: String,
+pub fn print_issues_table(issues: &[Issue], columns: &IssueColumns) {
+    let records = issues.iter().map(|issue| columns.record(issue)).collect();
+    print_record_list(
+        columns.header(),
+        records,
+        columns.max_width(),
+        "No issues found.",
+        "issue(s)",
+    );
 }
 
-impl From<&Issue> for IssueRow {
-    fn from(issue: &Issue) -> Self {
-        Self {
-            id: issue.id.clone(),
-            short_id: issue.short_id.clone(),
-            title: truncate_string(&issue.title, 50),
-            status: format_status_colored(&issue.status),
-            events: issue.count.clone(),
-            last_seen: format_relative_time(&issue.last_seen),
-        }
+/// Issue table printed page by page, for results fetched across pages
+pub fn issues_table_stream(columns: &IssueColumns) -> TableStream {
+    TableStream::new(&columns.widths())
+        .truncate(columns.truncate())
+        .max_width(columns.max_width())
+}
+
+pub fn print_issues_page(table: &mut TableStream, issues: &[Issue], columns: &IssueColumns) {
+    let records = issues.iter().map(|issue| columns.record(issue)).collect();
+    table.push_records(&columns.header(), records);
+}
+
+pub fn finish_issues_table(table: TableStream) {
+    if table.is_compact() {
+        table.finish();
+        return;
+    }
+    match table.finish() {
+        0 => println!("No issues found."),
+        count => println!("Showing {} issue(s)", count),
     }
 }
 
-pub fn print_issues_table(issues: &[Issue]) {
+/// Issues table for `issues list --watch`. Issues in `new` are marked, and
+/// issues in `rising` show how many events they gained since the last poll.
+pub fn print_issues_watch_table(
+    issues: &[Issue],
+    new: &HashSet<String>,
+    rising: &HashMap<String, u64>,
+    columns: &IssueColumns,
+) {
     if issues.is_empty() {
         println!("No issues found.");
         return;
     }
 
-    let rows: Vec<IssueRow> = issues.iter().map(IssueRow::from).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    // New issues are marked next to their short ID, or in the first column
+    // if that is not shown
+    let marked = columns.position(IssueField::ShortId).unwrap_or(0);
+    let events = columns.position(IssueField::Count);
+    let records = issues
+        .iter()
+        .map(|issue| {
+            let mut record = columns.record(issue);
+            if new.contains(&issue.id) {
+                record[marked] = format!("{} {}", record[marked].green().bold(), "NEW".green());
+            }
+            if let (Some(delta), Some(events)) = (rising.get(&issue.id), events) {
+                record[events] = format!(
+                    "{} {}",
+                    record[events],
+                    format!("(+{})", delta).yellow().bold()
+                );
+            }
+            record
+        })
+        .collect();
+    print_records(columns.header(), records, columns.max_width());
 
-    println!("{table}");
-    println!("Showing {} issue(s)", issues.len());
+    println!(
+        "Showing {} issue(s), {} new, {} with new events",
+        issues.len(),
+        new.len(),
+        rising.len()
+    );
 }
 
 pub fn print_issue_detail(issue: &Issue) {
+    if is_compact() {
+        let columns = IssueColumns::default();
+        print_records(columns.header(), vec![columns.record(issue)], None);
+        return;
+    }
+
     let separator = "=".repeat(80);
 
     println!();
@@ -100,6 +154,1041 @@ pub fn print_issue_detail(issue: &Issue) {
     println!();
 }
 
+#[derive(Tabled)]
+struct EventRow {
+    #[tabled(rename = "Event ID")]
+    event_id: String,
+    #[tabled(rename = "Date")]
+    date: String,
+    #[tabled(rename = "Message")]
+    message: String,
+    #[tabled(rename = "User")]
+    user: String,
+}
+
+impl From<&Event> for EventRow {
+    fn from(event: &Event) -> Self {
+        let message = event
+            .message
+            .as_deref()
+            .filter(|m| !m.is_empty())
+            .unwrap_or(&event.title);
+        Self {
+            event_id: event.event_id.clone(),
+            date: event
+                .date_created
+                .as_ref()
+                .map(format_relative_time)
+                .unwrap_or_default(),
+            message: truncate_string(message, 50),
+            user: event
+                .user
+                .as_ref()
+                .and_then(|u| u.email.clone().or_else(|| u.username.clone()))
+                .or_else(|| event.user.as_ref().and_then(|u| u.id.clone()))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Event table printed page by page, for results fetched across pages
+pub fn events_table_stream() -> TableStream {
+    TableStream::new(&[32, 12, 50, 30])
+}
+
+pub fn print_events_page(table: &mut TableStream, events: &[Event]) {
+    table.push(events.iter().map(EventRow::from).collect());
+}
+
+pub fn finish_events_table(table: TableStream) {
+    if table.is_compact() {
+        table.finish();
+        return;
+    }
+    match table.finish() {
+        0 => println!("No events found."),
+        count => println!("Showing {} event(s)", count),
+    }
+}
+
+pub fn print_event_detail(event: &Event, in_app_only: bool, breadcrumb_limit: usize) {
+    if is_compact() {
+        print_rows(vec![EventRow::from(event)]);
+        return;
+    }
+
+    let separator = "=".repeat(80);
+
+    println!();
+    println!("{}: {}", "Event".bold(), event.event_id.cyan());
+    println!("{separator}");
+    println!("{:<12} {}", "Title:".bold(), event.title);
+    if let Some(date) = &event.date_created {
+        println!(
+            "{:<12} {}",
+            "Date:".bold(),
+            date.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+    }
+    if let Some(platform) = &event.platform {
+        println!("{:<12} {}", "Platform:".bold(), platform);
+    }
+    if let Some(user) = &event.user {
+        let name = user
+            .email
+            .as_deref()
+            .or(user.username.as_deref())
+            .or(user.id.as_deref())
+            .or(user.ip_address.as_deref())
+            .unwrap_or("unknown");
+        println!("{:<12} {}", "User:".bold(), name);
+    }
+    if let Some(sdk) = &event.sdk {
+        println!("{:<12} {} {}", "SDK:".bold(), sdk.name, sdk.version);
+    }
+    if let Some(message) = event.formatted_message() {
+        println!("{:<12} {}", "Message:".bold(), message);
+    }
+
+    if !event.tags.is_empty() {
+        println!();
+        println!("{}", "Tags".bold());
+        for tag in &event.tags {
+            println!("  {:<24} {}", tag.key.dimmed(), tag.value);
+        }
+    }
+
+    if !event.contexts.is_empty() {
+        println!();
+        println!("{}", "Contexts".bold());
+        for (key, value) in &event.contexts {
+            println!("  {:<24} {}", key.dimmed(), format_context(value));
+        }
+    }
+
+    for exception in event.exceptions() {
+        println!();
+        print_exception(&exception, in_app_only);
+    }
+
+    let breadcrumbs = event.breadcrumbs();
+    if breadcrumb_limit > 0 && !breadcrumbs.is_empty() {
+        let skip = breadcrumbs.len().saturating_sub(breadcrumb_limit);
+        println!();
+        println!(
+            "{} (last {} of {})",
+            "Breadcrumbs".bold(),
+            breadcrumbs.len() - skip,
+            breadcrumbs.len()
+        );
+        for crumb in &breadcrumbs[skip..] {
+            let time = crumb
+                .timestamp
+                .map(|t| t.format("%H:%M:%S").to_string())
+                .unwrap_or_else(|| "--:--:--".to_string());
+            let category = crumb
+                .category
+                .as_deref()
+                .or(crumb.breadcrumb_type.as_deref())
+                .unwrap_or("default");
+            let level = crumb.level.as_deref().unwrap_or("info");
+            println!(
+                "  {} {:<16} {:<8} {}",
+                time.dimmed(),
+                category,
+                level,
+                crumb.message.as_deref().unwrap_or("")
+            );
+        }
+    }
+
+    println!();
+}
+
+fn print_exception(exception: &ExceptionValue, in_app_only: bool) {
+    let exception_type = exception.exception_type.as_deref().unwrap_or("Error");
+    match &exception.value {
+        Some(value) => println!("{}: {}", exception_type.red().bold(), value),
+        None => println!("{}", exception_type.red().bold()),
+    }
+
+    let Some(stacktrace) = &exception.stacktrace else {
+        return;
+    };
+
+    let has_in_app = stacktrace.frames.iter().any(|f| f.in_app);
+    let frames: Vec<&Frame> = stacktrace
+        .frames
+        .iter()
+        .filter(|f| !in_app_only || !has_in_app || f.in_app)
+        .collect();
+
+    println!("  {}", "Stack trace (most recent call last):".dimmed());
+    for frame in frames {
+        print_frame(frame);
+    }
+}
+
+fn print_frame(frame: &Frame) {
+    let location = frame
+        .filename
+        .as_deref()
+        .or(frame.abs_path.as_deref())
+        .or(frame.module.as_deref())
+        .unwrap_or("<unknown>");
+    let function = frame.function.as_deref().unwrap_or("?");
+    let position = match (frame.line_no, frame.col_no) {
+        (Some(line), Some(col)) => format!(" at line {}:{}", line, col),
+        (Some(line), None) => format!(" at line {}", line),
+        _ => String::new(),
+    };
+
+    let header = format!("{} in {}{}", location, function, position);
+    if frame.in_app {
+        println!("  {} {}", "›".yellow().bold(), header.bold());
+    } else {
+        println!("    {}", header.dimmed());
+    }
+
+    if let Some(line) = frame.context_line() {
+        let line = line.trim();
+        if frame.in_app {
+            println!("        {}", line);
+        } else {
+            println!("        {}", line.dimmed());
+        }
+    }
+}
+
+fn format_context(value: &serde_json::Value) -> String {
+    let field = |key: &str| value.get(key).and_then(|v| v.as_str());
+
+    match (field("name"), field("version")) {
+        (Some(name), Some(version)) => format!("{} {}", name, version),
+        (Some(name), None) => name.to_string(),
+        _ => value
+            .as_object()
+            .map(|obj| {
+                obj.iter()
+                    .filter(|(k, v)| *k != "type" && !v.is_object() && !v.is_array())
+                    .map(|(k, v)| match v.as_str() {
+                        Some(s) => format!("{}={}", k, s),
+                        None => format!("{}={}", k, v),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_else(|| value.to_string()),
+    }
+}
+
+#[derive(Tabled)]
+struct ReleaseRow {
+    #[tabled(rename = "Version")]
+    version: String,
+    #[tabled(rename = "Projects")]
+    projects: String,
+    #[tabled(rename = "Commits")]
+    commits: u64,
+    #[tabled(rename = "New Issues")]
+    new_issues: u64,
+    #[tabled(rename = "Released")]
+    released: String,
+    #[tabled(rename = "Created")]
+    created: String,
+}
+
+impl From<&Release> for ReleaseRow {
+    fn from(release: &Release) -> Self {
+        Self {
+            version: truncate_string(&release.version, 40),
+            projects: release
+                .projects
+                .iter()
+                .map(|p| p.slug.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            commits: release.commit_count,
+            new_issues: release.new_groups,
+            released: release
+                .date_released
+                .as_ref()
+                .map(format_relative_time)
+                .unwrap_or_else(|| "unreleased".yellow().to_string()),
+            created: format_relative_time(&release.date_created),
+        }
+    }
+}
+
+pub fn print_releases_table(releases: &[Release]) {
+    let rows: Vec<ReleaseRow> = releases.iter().map(ReleaseRow::from).collect();
+    print_list(rows, "No releases found.", "release(s)");
+}
+
+pub fn print_release_detail(release: &Release) {
+    if is_compact() {
+        print_rows(vec![ReleaseRow::from(release)]);
+        return;
+    }
+
+    let separator = "=".repeat(80);
+
+    println!();
+    println!("{}: {}", "Release".bold(), release.version.cyan());
+    println!("{separator}");
+    let projects: Vec<&str> = release.projects.iter().map(|p| p.slug.as_str()).collect();
+    println!("{:<12} {}", "Projects:".bold(), projects.join(", "));
+    println!(
+        "{:<12} {}",
+        "Created:".bold(),
+        release.date_created.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    match &release.date_released {
+        Some(date) => println!(
+            "{:<12} {}",
+            "Released:".bold(),
+            date.format("%Y-%m-%d %H:%M:%S UTC")
+        ),
+        None => println!("{:<12} {}", "Released:".bold(), "Unreleased".yellow()),
+    }
+    if let Some(git_ref) = &release.git_ref {
+        println!("{:<12} {}", "Ref:".bold(), git_ref);
+    }
+    println!();
+    println!("{:<12} {}", "Commits:".bold(), release.commit_count);
+    if let Some(commit) = &release.last_commit {
+        let summary = commit
+            .message
+            .as_deref()
+            .and_then(|m| m.lines().next())
+            .unwrap_or("");
+        let short_id: String = commit.id.chars().take(12).collect();
+        println!("{:<12} {} {}", "Last Commit:".bold(), short_id, summary);
+    }
+    println!("{:<12} {}", "New Issues:".bold(), release.new_groups);
+    println!("{:<12} {}", "Deploys:".bold(), release.deploy_count);
+
+    if let Some(url) = &release.url {
+        println!();
+        println!("{:<12} {}", "Link:".bold(), url.blue());
+    }
+    println!();
+}
+
+#[derive(Tabled)]
+struct DeployRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Environment")]
+    environment: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Finished")]
+    finished: String,
+    #[tabled(rename = "Duration")]
+    duration: String,
+}
+
+impl From<&Deploy> for DeployRow {
+    fn from(deploy: &Deploy) -> Self {
+        Self {
+            id: deploy.id.clone(),
+            environment: deploy.environment.clone(),
+            name: deploy.name.clone().unwrap_or_default(),
+            finished: format_relative_time(&deploy.date_finished),
+            duration: deploy
+                .date_started
+                .map(|started| format_duration(deploy.date_finished - started))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+pub fn print_deploys_table(deploys: &[Deploy]) {
+    let rows: Vec<DeployRow> = deploys.iter().map(DeployRow::from).collect();
+    print_list(rows, "No deploys found.", "deploy(s)");
+}
+
+fn format_duration(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3600 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}h {}m", seconds / 3600, (seconds % 3600) / 60)
+    }
+}
+
+#[derive(Tabled)]
+struct OrgRow {
+    #[tabled(rename = "Slug")]
+    slug: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Created")]
+    created: String,
+}
+
+impl From<&Organization> for OrgRow {
+    fn from(org: &Organization) -> Self {
+        Self {
+            slug: org.slug.clone(),
+            name: truncate_string(&org.name, 40),
+            status: org
+                .status
+                .as_ref()
+                .map(|s| s.id.clone())
+                .unwrap_or_default(),
+            created: org.date_created.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+pub fn print_orgs_table(orgs: &[Organization]) {
+    let rows: Vec<OrgRow> = orgs.iter().map(OrgRow::from).collect();
+    print_list(rows, "No organizations found.", "organization(s)");
+}
+
+pub fn print_org_detail(org: &Organization) {
+    if is_compact() {
+        print_rows(vec![OrgRow::from(org)]);
+        return;
+    }
+
+    let separator = "=".repeat(80);
+
+    println!();
+    println!("{}: {}", "Organization".bold(), org.slug.cyan());
+    println!("{separator}");
+    println!("{:<12} {}", "Name:".bold(), org.name);
+    println!("{:<12} {}", "ID:".bold(), org.id);
+    if let Some(status) = &org.status {
+        println!("{:<12} {}", "Status:".bold(), status.name);
+    }
+    println!(
+        "{:<12} {}",
+        "Created:".bold(),
+        org.date_created.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    if let Some(links) = &org.links {
+        println!();
+        println!("{:<12} {}", "Link:".bold(), links.organization_url.blue());
+    }
+    println!();
+}
+
+pub fn print_auth_status(
+    server: &str,
+    profile: &str,
+    source: &str,
+    info: &AuthInfo,
+    orgs: &[Organization],
+) {
+    let separator = "=".repeat(80);
+
+    println!();
+    match &info.user {
+        Some(user) => println!("{}: {}", "Logged in as".bold(), user.username.cyan()),
+        None => println!("{}", "Logged in with an organization token".bold()),
+    }
+    println!("{separator}");
+    if let Some(user) = &info.user {
+        if !user.name.is_empty() {
+            println!("{:<12} {}", "Name:".bold(), user.name);
+        }
+        if let Some(email) = &user.email {
+            println!("{:<12} {}", "Email:".bold(), email);
+        }
+    }
+    println!("{:<12} {}", "Server:".bold(), server);
+    println!("{:<12} {}", "Profile:".bold(), profile);
+    println!("{:<12} {}", "Token:".bold(), source);
+    if !info.scopes().is_empty() {
+        println!("{:<12} {}", "Scopes:".bold(), info.scopes().join(", "));
+    }
+    if !orgs.is_empty() {
+        let slugs: Vec<&str> = orgs.iter().map(|org| org.slug.as_str()).collect();
+        println!("{:<12} {}", "Orgs:".bold(), slugs.join(", "));
+    }
+    println!();
+}
+
+#[derive(Tabled)]
+struct TeamRow {
+    #[tabled(rename = "Slug")]
+    slug: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Members")]
+    members: u32,
+    #[tabled(rename = "Joined")]
+    joined: String,
+}
+
+impl From<&Team> for TeamRow {
+    fn from(team: &Team) -> Self {
+        Self {
+            slug: team.slug.clone(),
+            name: truncate_string(&team.name, 40),
+            members: team.member_count,
+            joined: if team.is_member { "yes" } else { "" }.to_string(),
+        }
+    }
+}
+
+pub fn print_teams_table(teams: &[Team]) {
+    let rows: Vec<TeamRow> = teams.iter().map(TeamRow::from).collect();
+    print_list(rows, "No teams found.", "team(s)");
+}
+
+#[derive(Tabled)]
+struct TeamMemberRow {
+    #[tabled(rename = "Email")]
+    email: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Role")]
+    role: String,
+    #[tabled(rename = "Team Role")]
+    team_role: String,
+}
+
+impl From<&TeamMember> for TeamMemberRow {
+    fn from(member: &TeamMember) -> Self {
+        let email = if member.pending {
+            format!("{} {}", member.email, "(invited)".dimmed())
+        } else {
+            member.email.clone()
+        };
+        Self {
+            email,
+            name: member.name.clone().unwrap_or_default(),
+            role: member.role.clone().unwrap_or_default(),
+            team_role: member.team_role.clone().unwrap_or_default(),
+        }
+    }
+}
+
+pub fn print_team_members_table(members: &[TeamMember]) {
+    let rows: Vec<TeamMemberRow> = members.iter().map(TeamMemberRow::from).collect();
+    print_list(rows, "No members found.", "member(s)");
+}
+
+#[derive(Tabled)]
+struct ProjectRow {
+    #[tabled(rename = "Slug")]
+    slug: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Platform")]
+    platform: String,
+    #[tabled(rename = "Teams")]
+    teams: String,
+    #[tabled(rename = "Created")]
+    created: String,
+}
+
+impl From<&Project> for ProjectRow {
+    fn from(project: &Project) -> Self {
+        let teams: Vec<&str> = project.teams.iter().map(|t| t.slug.as_str()).collect();
+        Self {
+            slug: project.slug.clone(),
+            name: truncate_string(&project.name, 40),
+            platform: project.platform.clone().unwrap_or_default(),
+            teams: teams.join(", "),
+            created: project.date_created.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+pub fn print_projects_table(projects: &[Project]) {
+    let rows: Vec<ProjectRow> = projects.iter().map(ProjectRow::from).collect();
+    print_list(rows, "No projects found.", "project(s)");
+}
+
+pub fn print_project_detail(project: &Project) {
+    if is_compact() {
+        print_rows(vec![ProjectRow::from(project)]);
+        return;
+    }
+
+    let separator = "=".repeat(80);
+
+    println!();
+    println!("{}: {}", "Project".bold(), project.slug.cyan());
+    println!("{separator}");
+    println!("{:<12} {}", "Name:".bold(), project.name);
+    println!("{:<12} {}", "ID:".bold(), project.id);
+    if let Some(platform) = &project.platform {
+        println!("{:<12} {}", "Platform:".bold(), platform);
+    }
+    if let Some(status) = &project.status {
+        println!("{:<12} {}", "Status:".bold(), status);
+    }
+    let teams: Vec<&str> = project.teams.iter().map(|t| t.slug.as_str()).collect();
+    if !teams.is_empty() {
+        println!("{:<12} {}", "Teams:".bold(), teams.join(", "));
+    }
+    println!(
+        "{:<12} {}",
+        "Created:".bold(),
+        project.date_created.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    println!();
+}
+
+#[derive(Tabled)]
+struct ProjectKeyRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Active")]
+    active: String,
+    #[tabled(rename = "DSN")]
+    dsn: String,
+}
+
+impl From<&ProjectKey> for ProjectKeyRow {
+    fn from(key: &ProjectKey) -> Self {
+        Self {
+            id: key.id.clone(),
+            name: key.name.clone(),
+            active: if key.is_active {
+                "yes".green().to_string()
+            } else {
+                "no".dimmed().to_string()
+            },
+            dsn: key.dsn.public.clone(),
+        }
+    }
+}
+
+pub fn print_project_keys_table(keys: &[ProjectKey]) {
+    let rows: Vec<ProjectKeyRow> = keys.iter().map(ProjectKeyRow::from).collect();
+    print_list(rows, "No client keys found.", "key(s)");
+}
+
+#[derive(Tabled)]
+struct MonitorRow {
+    #[tabled(rename = "Slug")]
+    slug: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Schedule")]
+    schedule: String,
+    #[tabled(rename = "Last Check-in")]
+    last_check_in: String,
+}
+
+impl From<&Monitor> for MonitorRow {
+    fn from(monitor: &Monitor) -> Self {
+        Self {
+            slug: monitor.slug.clone(),
+            name: truncate_string(&monitor.name, 40),
+            status: format_monitor_status(monitor),
+            schedule: monitor.config.schedule_display(),
+            last_check_in: monitor
+                .last_check_in()
+                .map(|dt| format_relative_time(&dt))
+                .unwrap_or_else(|| "never".to_string()),
+        }
+    }
+}
+
+pub fn print_monitors_table(monitors: &[Monitor]) {
+    let rows: Vec<MonitorRow> = monitors.iter().map(MonitorRow::from).collect();
+    print_list(rows, "No monitors found.", "monitor(s)");
+}
+
+pub fn print_monitor_detail(monitor: &Monitor) {
+    if is_compact() {
+        print_rows(vec![MonitorRow::from(monitor)]);
+        return;
+    }
+
+    let separator = "=".repeat(80);
+
+    println!();
+    println!("{}: {}", "Monitor".bold(), monitor.slug.cyan());
+    println!("{separator}");
+    println!("{:<12} {}", "Name:".bold(), monitor.name);
+    println!(
+        "{:<12} {}",
+        "Status:".bold(),
+        format_monitor_status(monitor)
+    );
+    if let Some(project) = &monitor.project {
+        println!("{:<12} {}", "Project:".bold(), project.slug);
+    }
+    println!(
+        "{:<12} {}",
+        "Schedule:".bold(),
+        monitor.config.schedule_display()
+    );
+    if let Some(timezone) = &monitor.config.timezone {
+        println!("{:<12} {}", "Timezone:".bold(), timezone);
+    }
+    if let Some(margin) = monitor.config.checkin_margin {
+        println!("{:<12} {} min", "Margin:".bold(), margin);
+    }
+    if let Some(runtime) = monitor.config.max_runtime {
+        println!("{:<12} {} min", "Max Runtime:".bold(), runtime);
+    }
+    if let Some(next) = monitor.next_check_in() {
+        println!(
+            "{:<12} {}",
+            "Next:".bold(),
+            next.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+    }
+    for env in &monitor.environments {
+        println!(
+            "{:<12} {} ({})",
+            "Environment:".bold(),
+            env.name,
+            env.status
+        );
+    }
+    println!();
+}
+
+fn format_monitor_status(monitor: &Monitor) -> String {
+    if monitor.is_muted {
+        return "muted".dimmed().to_string();
+    }
+    // The monitor status only says whether it is enabled; health is per environment
+    let failing = monitor
+        .environments
+        .iter()
+        .any(|e| matches!(e.status.as_str(), "error" | "missed_checkin" | "timeout"));
+    match monitor.status.as_str() {
+        "active" if failing => "failing".red().to_string(),
+        "active" => "ok".green().to_string(),
+        other => other.yellow().to_string(),
+    }
+}
+
+#[derive(Tabled)]
+struct CheckInRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Environment")]
+    environment: String,
+    #[tabled(rename = "Duration")]
+    duration: String,
+    #[tabled(rename = "Date")]
+    date: String,
+}
+
+impl From<&CheckIn> for CheckInRow {
+    fn from(checkin: &CheckIn) -> Self {
+        let status = match checkin.status {
+            CheckInStatus::Ok => "ok".green().to_string(),
+            CheckInStatus::InProgress => "in_progress".cyan().to_string(),
+            other => other.to_string().red().to_string(),
+        };
+        Self {
+            id: checkin.id.clone(),
+            status,
+            environment: checkin.environment.clone().unwrap_or_default(),
+            duration: checkin
+                .duration
+                .map(|ms| format_duration(chrono::Duration::milliseconds(ms as i64)))
+                .unwrap_or_default(),
+            date: format_relative_time(&checkin.date_created),
+        }
+    }
+}
+
+pub fn print_checkins_table(checkins: &[CheckIn]) {
+    let rows: Vec<CheckInRow> = checkins.iter().map(CheckInRow::from).collect();
+    print_list(rows, "No check-ins found.", "check-in(s)");
+}
+
+#[derive(Tabled)]
+struct ProfileRow {
+    #[tabled(rename = "")]
+    active: String,
+    #[tabled(rename = "Profile")]
+    name: String,
+    #[tabled(rename = "Server")]
+    server: String,
+    #[tabled(rename = "Organization")]
+    org: String,
+    #[tabled(rename = "Token")]
+    token: String,
+}
+
+#[derive(Tabled)]
+struct ConfigRow {
+    #[tabled(rename = "Key")]
+    key: String,
+    #[tabled(rename = "Value")]
+    value: String,
+    #[tabled(rename = "Origin")]
+    origin: String,
+}
+
+pub fn print_config_table(values: &[ConfigValue]) {
+    let rows: Vec<ConfigRow> = values
+        .iter()
+        .map(|v| ConfigRow {
+            key: v.key.to_string(),
+            value: v.value.clone().unwrap_or_default(),
+            origin: v.origin.clone().unwrap_or_default(),
+        })
+        .collect();
+    print_rows(rows);
+}
+
+pub fn print_profiles_table(profiles: &[(String, Settings)], active: &str) {
+    let rows: Vec<ProfileRow> = profiles
+        .iter()
+        .map(|(name, settings)| ProfileRow {
+            active: if name == active { "*" } else { "" }.to_string(),
+            name: name.clone(),
+            server: settings.server_url.clone().unwrap_or_default(),
+            org: settings.default_org.clone().unwrap_or_default(),
+            token: if settings.auth_token.is_some() || settings.token_storage.is_some() {
+                "set"
+            } else {
+                ""
+            }
+            .to_string(),
+        })
+        .collect();
+    print_rows(rows);
+}
+
+#[derive(Tabled)]
+struct DifRow {
+    #[tabled(rename = "Debug ID")]
+    debug_id: String,
+    #[tabled(rename = "Kind")]
+    kind: String,
+    #[tabled(rename = "Arch")]
+    arch: String,
+    #[tabled(rename = "Features")]
+    features: String,
+    #[tabled(rename = "Path")]
+    path: String,
+}
+
+impl From<&DifFile> for DifRow {
+    fn from(dif: &DifFile) -> Self {
+        Self {
+            debug_id: dif.debug_id.clone().unwrap_or_else(|| "-".to_string()),
+            kind: dif.kind.to_string(),
+            arch: dif.arch.clone(),
+            features: dif.features.join(", "),
+            path: dif.path.display().to_string(),
+        }
+    }
+}
+
+pub fn print_difs_table(difs: &[DifFile]) {
+    if difs.is_empty() && !is_compact() {
+        println!("No debug information files found.");
+        return;
+    }
+
+    let rows: Vec<DifRow> = difs.iter().map(DifRow::from).collect();
+    print_rows(rows);
+    if !is_compact() {
+        println!("Found {} debug file(s)", difs.len());
+    }
+}
+
+pub fn print_dif_detail(dif: &DifFile) {
+    if is_compact() {
+        print_rows(vec![DifRow::from(dif)]);
+        return;
+    }
+
+    println!();
+    println!(
+        "{}: {}",
+        "Debug File".bold(),
+        dif.path.display().to_string().cyan()
+    );
+    println!("{}", "=".repeat(80));
+    println!("{:<12} {}", "Kind:".bold(), dif.kind);
+    println!("{:<12} {}", "Arch:".bold(), dif.arch);
+    match &dif.debug_id {
+        Some(id) => println!("{:<12} {}", "Debug ID:".bold(), id),
+        None => println!("{:<12} {}", "Debug ID:".bold(), "missing".red()),
+    }
+    if let Some(code_id) = &dif.code_id {
+        println!("{:<12} {}", "Code ID:".bold(), code_id);
+    }
+    if dif.features.is_empty() {
+        println!("{:<12} {}", "Features:".bold(), "none".dimmed());
+    } else {
+        println!("{:<12} {}", "Features:".bold(), dif.features.join(", "));
+    }
+    println!();
+}
+
+#[derive(Tabled)]
+struct DifUploadRow {
+    #[tabled(rename = "File")]
+    file: String,
+    #[tabled(rename = "Kind")]
+    kind: String,
+    #[tabled(rename = "Debug ID")]
+    debug_id: String,
+    #[tabled(rename = "Status")]
+    status: String,
+}
+
+impl From<&UploadResult> for DifUploadRow {
+    fn from(result: &UploadResult) -> Self {
+        let status = match result.status {
+            UploadStatus::Uploaded => "uploaded".green().to_string(),
+            UploadStatus::Exists => "already on server".dimmed().to_string(),
+            UploadStatus::Processing => "processing".cyan().to_string(),
+            UploadStatus::Error => match &result.detail {
+                Some(detail) => format!("{}: {}", "error".red(), detail),
+                None => "error".red().to_string(),
+            },
+        };
+        Self {
+            file: result.path.display().to_string(),
+            kind: result.kind.to_string(),
+            debug_id: result.debug_id.clone(),
+            status,
+        }
+    }
+}
+
+pub fn print_dif_upload_table(results: &[UploadResult]) {
+    let rows: Vec<DifUploadRow> = results.iter().map(DifUploadRow::from).collect();
+    print_rows(rows);
+    if is_compact() {
+        return;
+    }
+
+    let existing = results
+        .iter()
+        .filter(|r| r.status == UploadStatus::Exists)
+        .count();
+    println!(
+        "Uploaded {} debug file(s), {} already on the server",
+        results.len() - existing,
+        existing
+    );
+}
+
+/// Print rows as a table, or one line per row in compact mode
+fn print_rows<R: Tabled>(rows: Vec<R>) {
+    let (header, records) = tabled_records(rows);
+    print_records(header, records, None);
+}
+
+/// Print rows as a table followed by their count, or `empty` if there are
+/// none. Compact output is only the rows.
+fn print_list<R: Tabled>(rows: Vec<R>, empty: &str, noun: &str) {
+    let (header, records) = tabled_records(rows);
+    print_record_list(header, records, None, empty, noun);
+}
+
+fn tabled_records<R: Tabled>(rows: Vec<R>) -> (Vec<String>, Vec<Vec<String>>) {
+    let header = R::headers().into_iter().map(|h| h.into_owned()).collect();
+    let records = rows
+        .iter()
+        .map(|row| row.fields().into_iter().map(|f| f.into_owned()).collect())
+        .collect();
+    (header, records)
+}
+
+/// Like `print_rows`, for records of text. The widest columns are cut to
+/// keep the table within `max_width`.
+fn print_records(header: Vec<String>, records: Vec<Vec<String>>, max_width: Option<usize>) {
+    let mut table = if is_compact() {
+        compact_table(records)
+    } else {
+        rounded_table(header, records)
+    };
+    if let Some(width) = max_width {
+        fit_width(&mut table, width);
+    }
+
+    if is_compact() {
+        print_compact(&table);
+    } else {
+        println!("{table}");
+    }
+}
+
+fn print_record_list(
+    header: Vec<String>,
+    records: Vec<Vec<String>>,
+    max_width: Option<usize>,
+    empty: &str,
+    noun: &str,
+) {
+    if is_compact() {
+        print_records(header, records, max_width);
+    } else if records.is_empty() {
+        println!("{empty}");
+    } else {
+        let count = records.len();
+        print_records(header, records, max_width);
+        println!("Showing {} {}", count, noun);
+    }
+}
+
+pub(super) fn rounded_table(header: Vec<String>, records: Vec<Vec<String>>) -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(header);
+    for record in records {
+        builder.push_record(record);
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    table
+}
+
+/// Rows without a header or borders, columns separated by two spaces
+pub(super) fn compact_table(records: Vec<Vec<String>>) -> Table {
+    let mut builder = Builder::default();
+    for record in records {
+        builder.push_record(record);
+    }
+    let mut table = builder.build();
+    table.with(Style::empty()).with(Padding::new(0, 2, 0, 0));
+    table
+}
+
+/// Cut the text of the widest columns until the table is at most `width`
+/// wide
+pub(super) fn fit_width(table: &mut Table, width: usize) {
+    table.with(
+        Width::truncate(width)
+            .suffix("...")
+            .priority(Priority::max(true)),
+    );
+}
+
+pub(super) fn print_compact(table: &Table) {
+    if table.count_rows() == 0 {
+        return;
+    }
+    let mut out = io::stdout().lock();
+    for line in table.to_string().lines() {
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    let _ = out.flush();
+}
+
 fn format_status(status: &crate::api::models::IssueStatus) -> String {
     match status {
         crate::api::models::IssueStatus::Resolved => "Resolved".green().to_string(),
@@ -109,7 +1198,7 @@ fn format_status(status: &crate::api::models::IssueStatus) -> String {
     }
 }
 
-fn format_status_colored(status: &crate::api::models::IssueStatus) -> String {
+pub(super) fn format_status_colored(status: &crate::api::models::IssueStatus) -> String {
     match status {
         crate::api::models::IssueStatus::Resolved => "resolved".green().to_string(),
         crate::api::models::IssueStatus::Unresolved => "unresolved".red().to_string(),
@@ -118,7 +1207,7 @@ fn format_status_colored(status: &crate::api::models::IssueStatus) -> String {
     }
 }
 
-fn truncate_string(s: &str, max_len: usize) -> String {
+pub(super) fn truncate_string(s: &str, max_len: usize) -> String {
     if s.chars().count() <= max_len {
         s.to_string()
     } else {
@@ -127,7 +1216,7 @@ fn truncate_string(s: &str, max_len: usize) -> String {
     }
 }
 
-fn format_relative_time(dt: &DateTime<Utc>) -> String {
+pub fn format_relative_time(dt: &DateTime<Utc>) -> String {
     let now = Utc::now();
     let duration = now.signed_duration_since(*dt);
 
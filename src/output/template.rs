@@ -0,0 +1,149 @@
+use super::table::{format_relative_time, truncate_string};
+use crate::error::{Result, SentryCliError};
+use chrono::{DateTime, Utc};
+use colored::{Color, Colorize};
+use handlebars::{
+    handlebars_helper, Context, Handlebars, Helper, HelperResult, Output, RenderContext,
+    RenderErrorReason,
+};
+use serde_json::{Map, Value};
+use std::io::{self, Write};
+use std::sync::OnceLock;
+
+const NAME: &str = "output";
+
+/// Template given by `--template` or `--template-file`, if any
+static TEMPLATE: OnceLock<Handlebars<'static>> = OnceLock::new();
+
+/// Compile the template that replaces the output format for the rest of the
+/// run. Syntax errors are reported before any request is made.
+pub fn set_template(source: &str) -> Result<()> {
+    let mut registry = Handlebars::new();
+    registry.register_escape_fn(handlebars::no_escape);
+    registry.register_helper("relative", Box::new(relative));
+    registry.register_helper("truncate", Box::new(truncate));
+    registry.register_helper("color", Box::new(color));
+    registry
+        .register_template_string(NAME, source)
+        .map_err(|e| SentryCliError::Validation(e.to_string()))?;
+
+    let _ = TEMPLATE.set(registry);
+    Ok(())
+}
+
+pub(super) fn is_set() -> bool {
+    TEMPLATE.get().is_some()
+}
+
+/// Render the template once per record, each on its own line
+pub(super) fn print_records(records: &[Value]) -> Result<()> {
+    let Some(registry) = TEMPLATE.get() else {
+        return Ok(());
+    };
+
+    let mut out = io::stdout().lock();
+    for record in records {
+        let text = match registry.render(NAME, &with_snake_case(record)) {
+            Ok(text) => text,
+            Err(e) => {
+                let _ = out.flush();
+                return Err(SentryCliError::Validation(format!("Template error: {}", e)));
+            }
+        };
+        if text.ends_with('\n') {
+            let _ = write!(out, "{}", text);
+        } else {
+            let _ = writeln!(out, "{}", text);
+        }
+    }
+    let _ = out.flush();
+    Ok(())
+}
+
+/// Add a snake_case name for every camelCase field, so that templates can
+/// use `short_id` as well as the JSON name `shortId`
+fn with_snake_case(value: &Value) -> Value {
+    match value {
+        Value::Object(fields) => {
+            let mut result = Map::new();
+            for (key, value) in fields {
+                result.insert(key.clone(), with_snake_case(value));
+            }
+            for (key, value) in fields {
+                let snake = snake_case(key);
+                if !result.contains_key(&snake) {
+                    result.insert(snake, with_snake_case(value));
+                }
+            }
+            Value::Object(result)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(with_snake_case).collect()),
+        value => value.clone(),
+    }
+}
+
+/// `shortId` to `short_id`; a run of capitals is one word, as in `eventID`
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if !prev.is_ascii_uppercase() || next_lower {
+                result.push('_');
+            }
+        }
+        result.push(c.to_ascii_lowercase());
+    }
+    result
+}
+
+/// Text of a value: strings as they are, nothing for null, JSON otherwise
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+handlebars_helper!(relative: |value: Json| {
+    let value = text(value);
+    match value.parse::<DateTime<Utc>>() {
+        Ok(time) => format_relative_time(&time),
+        Err(_) => value,
+    }
+});
+
+handlebars_helper!(truncate: |value: Json, length: u64| {
+    let value = text(value);
+    let length = usize::try_from(length).unwrap_or(usize::MAX);
+    // Too short for the "..." suffix
+    if length <= 3 {
+        value.chars().take(length).collect()
+    } else {
+        truncate_string(&value, length)
+    }
+});
+
+/// `{{color value "red"}}`: the value in a color known to `colored`
+fn color(
+    h: &Helper<'_>,
+    _: &Handlebars<'_>,
+    _: &Context,
+    _: &mut RenderContext<'_, '_>,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = h.param(0).map(|p| text(p.value())).unwrap_or_default();
+    let name = h
+        .param(1)
+        .and_then(|p| p.value().as_str())
+        .ok_or(RenderErrorReason::ParamNotFoundForIndex("color", 1))?;
+    let color: Color = name
+        .parse()
+        .map_err(|_| RenderErrorReason::Other(format!("Unknown color: {}", name)))?;
+
+    out.write(&value.color(color).to_string())?;
+    Ok(())
+}
@@ -0,0 +1,278 @@
+use super::{dwarf, hex};
+use crate::error::{Result, SentryCliError};
+use object::{Architecture, Object, ObjectSection, SectionKind};
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+use walkdir::WalkDir;
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const BREAKPAD_MAGIC: &[u8] = b"MODULE ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DifKind {
+    /// ELF executable or shared library
+    Elf,
+    /// ELF debug companion file (e.g. from `objcopy --only-keep-debug`)
+    ElfDebug,
+    /// Split DWARF object (`.dwo`) or package (`.dwp`)
+    SplitDwarf,
+    /// Breakpad text symbol file (`.sym`)
+    Breakpad,
+}
+
+impl std::fmt::Display for DifKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DifKind::Elf => write!(f, "elf"),
+            DifKind::ElfDebug => write!(f, "elf debug companion"),
+            DifKind::SplitDwarf => write!(f, "split dwarf"),
+            DifKind::Breakpad => write!(f, "breakpad"),
+        }
+    }
+}
+
+/// A debug information file found on disk
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DifFile {
+    pub path: PathBuf,
+    pub kind: DifKind,
+    pub arch: String,
+    pub debug_id: Option<String>,
+    pub code_id: Option<String>,
+    /// Capabilities the file provides: `symtab`, `debug`, `unwind`
+    pub features: Vec<&'static str>,
+}
+
+impl DifFile {
+    pub fn name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+/// Inspect a single file. Returns `None` if it is not a supported debug file.
+pub fn inspect(path: &Path) -> Result<Option<DifFile>> {
+    let mut magic = [0u8; 7];
+    let read = File::open(path)?.read(&mut magic)?;
+    let magic = &magic[..read];
+
+    if magic.starts_with(ELF_MAGIC) {
+        inspect_elf(path, &fs::read(path)?).map(Some)
+    } else if magic.starts_with(BREAKPAD_MAGIC) {
+        inspect_breakpad(path).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Recursively scan paths for debug information files
+pub fn scan(paths: &[PathBuf]) -> Result<Vec<DifFile>> {
+    let mut difs = Vec::new();
+
+    for path in paths {
+        if !path.exists() {
+            return Err(SentryCliError::Validation(format!(
+                "Path does not exist: {}",
+                path.display()
+            )));
+        }
+
+        for entry in WalkDir::new(path).sort_by_file_name() {
+            let entry = entry.map_err(|e| SentryCliError::Io(e.into()))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            // Unparseable files are skipped rather than aborting the scan
+            if let Ok(Some(dif)) = inspect(entry.path()) {
+                difs.push(dif);
+            }
+        }
+    }
+
+    Ok(difs)
+}
+
+fn inspect_elf(path: &Path, data: &[u8]) -> Result<DifFile> {
+    let file = object::File::parse(data).map_err(|e| {
+        SentryCliError::Validation(format!("Invalid ELF file {}: {}", path.display(), e))
+    })?;
+
+    let has_section = |name: &str| file.section_by_name(name).is_some();
+    let has_dwo = file
+        .sections()
+        .any(|s| s.name().is_ok_and(|n| n.ends_with(".dwo")));
+    // Debug companions keep section headers but strip the code itself
+    let text_stripped = file
+        .section_by_name(".text")
+        .is_some_and(|s| s.kind() == SectionKind::UninitializedData);
+
+    let kind = if has_dwo {
+        DifKind::SplitDwarf
+    } else if text_stripped && has_section(".debug_info") {
+        DifKind::ElfDebug
+    } else {
+        DifKind::Elf
+    };
+
+    let mut features = Vec::new();
+    if has_section(".symtab") || has_section(".dynsym") {
+        features.push("symtab");
+    }
+    if has_section(".debug_info") || has_dwo {
+        features.push("debug");
+    }
+    if has_section(".eh_frame") || has_section(".debug_frame") {
+        features.push("unwind");
+    }
+
+    let build_id = file.build_id().ok().flatten();
+    // Split DWARF files have no build ID; their DWO ID identifies them instead
+    let debug_id = match build_id {
+        Some(id) => Some(debug_id_from_build_id(id, file.is_little_endian())),
+        None if kind == DifKind::SplitDwarf => split_dwarf_id(&file).map(debug_id_from_dwo_id),
+        None => None,
+    };
+
+    Ok(DifFile {
+        path: path.to_path_buf(),
+        kind,
+        arch: arch_name(file.architecture()).to_string(),
+        debug_id,
+        code_id: build_id.map(hex),
+        features,
+    })
+}
+
+fn inspect_breakpad(path: &Path) -> Result<DifFile> {
+    let mut line = String::new();
+    BufReader::new(File::open(path)?).read_line(&mut line)?;
+
+    // MODULE <os> <arch> <id> <name>
+    let mut parts = line.split_whitespace().skip(1);
+    let invalid = || {
+        SentryCliError::Validation(format!(
+            "Invalid Breakpad MODULE record in {}",
+            path.display()
+        ))
+    };
+    let _os = parts.next().ok_or_else(invalid)?;
+    let arch = parts.next().ok_or_else(invalid)?;
+    let id = parts.next().ok_or_else(invalid)?;
+
+    Ok(DifFile {
+        path: path.to_path_buf(),
+        kind: DifKind::Breakpad,
+        arch: arch.to_lowercase(),
+        debug_id: debug_id_from_breakpad(id),
+        code_id: None,
+        features: vec!["symtab", "unwind"],
+    })
+}
+
+/// Convert a GNU build ID to a Sentry debug ID. The first 16 bytes are read
+/// as a GUID, whose first three fields are stored in the file's byte order.
+fn debug_id_from_build_id(build_id: &[u8], little_endian: bool) -> String {
+    let mut bytes = [0u8; 16];
+    let len = build_id.len().min(16);
+    bytes[..len].copy_from_slice(&build_id[..len]);
+
+    if little_endian {
+        bytes[0..4].reverse();
+        bytes[4..6].reverse();
+        bytes[6..8].reverse();
+    }
+
+    Uuid::from_bytes(bytes).to_string()
+}
+
+/// DWO ID of the first unit of a split DWARF object or package
+fn split_dwarf_id(file: &object::File) -> Option<u64> {
+    let info = file.section_by_name(".debug_info.dwo")?;
+    let abbrev = file.section_by_name(".debug_abbrev.dwo")?;
+    dwarf::dwo_id(
+        &info.uncompressed_data().ok()?,
+        &abbrev.uncompressed_data().ok()?,
+        file.is_little_endian(),
+    )
+}
+
+/// A DWO ID is 8 bytes; as a debug ID they come first, most significant
+/// byte first, as tools print the ID
+fn debug_id_from_dwo_id(dwo_id: u64) -> String {
+    let mut bytes = [0u8; 16];
+    bytes[..8].copy_from_slice(&dwo_id.to_be_bytes());
+    Uuid::from_bytes(bytes).to_string()
+}
+
+/// Breakpad IDs are 32 hex digits of GUID followed by a hex age
+fn debug_id_from_breakpad(id: &str) -> Option<String> {
+    if id.len() < 33 || !id.is_ascii() {
+        return None;
+    }
+    let uuid = Uuid::parse_str(&id[..32]).ok()?;
+    let age = u32::from_str_radix(&id[32..], 16).ok()?;
+
+    Some(if age == 0 {
+        uuid.to_string()
+    } else {
+        format!("{}-{:x}", uuid, age)
+    })
+}
+
+/// Normalize a debug ID given on the command line for comparison
+pub fn normalize_debug_id(id: &str) -> String {
+    let id = id.trim().to_lowercase();
+    match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid.to_string(),
+        Err(_) => debug_id_from_breakpad(&id).unwrap_or(id),
+    }
+}
+
+fn arch_name(arch: Architecture) -> &'static str {
+    match arch {
+        Architecture::X86_64 | Architecture::X86_64_X32 => "x86_64",
+        Architecture::I386 => "x86",
+        Architecture::Aarch64 | Architecture::Aarch64_Ilp32 => "arm64",
+        Architecture::Arm => "arm",
+        Architecture::Mips => "mips",
+        Architecture::Mips64 => "mips64",
+        Architecture::PowerPc => "ppc",
+        Architecture::PowerPc64 => "ppc64",
+        Architecture::Riscv32 => "riscv32",
+        Architecture::Riscv64 => "riscv64",
+        Architecture::S390x => "s390x",
+        Architecture::Wasm32 => "wasm32",
+        _ => "unknown",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadStatus {
+    /// Sent to the server in this run
+    Uploaded,
+    /// Already present on the server
+    Exists,
+    /// Uploaded, still being processed
+    Processing,
+    Error,
+}
+
+/// Outcome of uploading one debug file
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadResult {
+    pub path: PathBuf,
+    pub kind: DifKind,
+    pub debug_id: String,
+    pub status: UploadStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
@@ -0,0 +1,179 @@
+//! Just enough DWARF parsing to find the DWO ID of a split DWARF file, which
+//! has no build ID of its own
+
+const DW_UT_SKELETON: u8 = 0x04;
+const DW_UT_SPLIT_COMPILE: u8 = 0x05;
+const DW_AT_GNU_DWO_ID: u64 = 0x2131;
+const DW_FORM_DATA8: u64 = 0x07;
+const DW_FORM_IMPLICIT_CONST: u64 = 0x21;
+
+/// Reads values in the byte order of the file, failing past the end
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    little_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], offset: usize, little_endian: bool) -> Self {
+        Self {
+            data,
+            offset,
+            little_endian,
+        }
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(len)?;
+        let bytes = self.data.get(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: u64) -> Option<()> {
+        self.bytes(usize::try_from(len).ok()?).map(|_| ())
+    }
+
+    fn uint(&mut self, len: usize) -> Option<u64> {
+        let bytes = self.bytes(len)?;
+        let mut value = 0u64;
+        for i in 0..len {
+            let byte = if self.little_endian {
+                bytes[len - 1 - i]
+            } else {
+                bytes[i]
+            };
+            value = (value << 8) | u64::from(byte);
+        }
+        Some(value)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.bytes(1).map(|b| b[0])
+    }
+
+    fn uleb(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.u8()?;
+            if shift < 64 {
+                value |= u64::from(byte & 0x7f) << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+            shift += 7;
+        }
+    }
+
+    fn sleb(&mut self) -> Option<()> {
+        self.uleb().map(|_| ())
+    }
+
+    fn cstr(&mut self) -> Option<()> {
+        let len = self.data.get(self.offset..)?.iter().position(|b| *b == 0)?;
+        self.skip(len as u64 + 1)
+    }
+}
+
+/// DWO ID of the first unit in `.debug_info.dwo`, which is also recorded in
+/// the skeleton unit of the binary the file belongs to. DWARF 5 stores it in
+/// the unit header; DWARF 4 in the `DW_AT_GNU_dwo_id` attribute of the
+/// compile unit.
+pub(super) fn dwo_id(info: &[u8], abbrev: &[u8], little_endian: bool) -> Option<u64> {
+    let mut unit = Reader::new(info, 0, little_endian);
+    let offset_size = match unit.uint(4)? {
+        0xffff_ffff => {
+            unit.skip(8)?;
+            8
+        }
+        _ => 4,
+    };
+    let version = unit.uint(2)?;
+
+    if version >= 5 {
+        let unit_type = unit.u8()?;
+        unit.skip(1 + offset_size as u64)?;
+        return match unit_type {
+            DW_UT_SKELETON | DW_UT_SPLIT_COMPILE => unit.uint(8),
+            _ => None,
+        };
+    }
+
+    let abbrev_offset = usize::try_from(unit.uint(offset_size)?).ok()?;
+    let address_size = usize::from(unit.u8()?);
+    let code = unit.uleb()?;
+
+    // Walk the attributes of the unit's first entry up to the DWO ID
+    let mut abbrevs = Reader::new(abbrev, abbrev_offset, little_endian);
+    skip_to_abbrev(&mut abbrevs, code)?;
+    loop {
+        let name = abbrevs.uleb()?;
+        let form = abbrevs.uleb()?;
+        if name == 0 && form == 0 {
+            return None;
+        }
+        if form == DW_FORM_IMPLICIT_CONST {
+            abbrevs.sleb()?;
+        }
+        if name == DW_AT_GNU_DWO_ID && form == DW_FORM_DATA8 {
+            return unit.uint(8);
+        }
+        skip_form(&mut unit, form, offset_size, address_size)?;
+    }
+}
+
+/// Move past the tag and children flag of the abbreviation `code`
+fn skip_to_abbrev(abbrevs: &mut Reader, code: u64) -> Option<()> {
+    loop {
+        let current = abbrevs.uleb()?;
+        if current == 0 {
+            return None;
+        }
+        abbrevs.uleb()?;
+        abbrevs.u8()?;
+        if current == code {
+            return Some(());
+        }
+        loop {
+            let name = abbrevs.uleb()?;
+            let form = abbrevs.uleb()?;
+            if name == 0 && form == 0 {
+                break;
+            }
+            if form == DW_FORM_IMPLICIT_CONST {
+                abbrevs.sleb()?;
+            }
+        }
+    }
+}
+
+/// Skip an attribute value of the given form; `None` for unknown forms
+fn skip_form(unit: &mut Reader, form: u64, offset_size: usize, address_size: usize) -> Option<()> {
+    let fixed = |len: usize| Some(len as u64);
+    let len = match form {
+        0x01 => fixed(address_size),
+        0x0b | 0x0c | 0x11 | 0x25 | 0x29 => fixed(1),
+        0x05 | 0x12 | 0x26 | 0x2a => fixed(2),
+        0x27 | 0x2b => fixed(3),
+        0x06 | 0x13 | 0x1c | 0x28 | 0x2c => fixed(4),
+        0x07 | 0x14 | 0x20 => fixed(8),
+        0x1e => fixed(16),
+        0x0e | 0x10 | 0x17 | 0x1d | 0x1f | 0x1f20 | 0x1f21 => fixed(offset_size),
+        0x19 | 0x21 => fixed(0),
+        // Variable-length constants and indexes
+        0x0d => return unit.sleb(),
+        0x0f | 0x15 | 0x1a | 0x1b | 0x22 | 0x23 | 0x1f01 | 0x1f02 => {
+            return unit.uleb().map(|_| ())
+        }
+        0x08 => return unit.cstr(),
+        // Blocks, preceded by their length
+        0x09 | 0x18 => unit.uleb(),
+        0x0a => unit.uint(1),
+        0x03 => unit.uint(2),
+        0x04 => unit.uint(4),
+        _ => None,
+    }?;
+    unit.skip(len)
+}
@@ -0,0 +1,105 @@
+pub mod dif;
+mod dwarf;
+pub mod sourcemaps;
+
+use crate::api::models::ChunkUploadOptions;
+use crate::api::SentryClient;
+use crate::error::{Result, SentryCliError};
+use crate::output::Progress;
+use sha1::{Digest, Sha1};
+
+/// A file split into chunks for the chunk-upload endpoint
+pub struct ChunkedFile {
+    data: Vec<u8>,
+    chunk_size: usize,
+    pub checksum: String,
+    pub chunk_checksums: Vec<String>,
+}
+
+impl ChunkedFile {
+    pub fn new(data: Vec<u8>, options: &ChunkUploadOptions) -> Result<Self> {
+        if options.hash_algorithm != "sha1" {
+            return Err(SentryCliError::Validation(format!(
+                "Unsupported chunk hash algorithm: {}",
+                options.hash_algorithm
+            )));
+        }
+
+        let chunk_size = options.chunk_size.max(1);
+        let checksum = sha1_hex(&data);
+        let chunk_checksums = data.chunks(chunk_size).map(sha1_hex).collect();
+
+        Ok(Self {
+            data,
+            chunk_size,
+            checksum,
+            chunk_checksums,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn chunks(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.chunk_checksums
+            .iter()
+            .map(String::as_str)
+            .zip(self.data.chunks(self.chunk_size))
+    }
+}
+
+pub fn sha1_hex(data: &[u8]) -> String {
+    hex(&Sha1::digest(data))
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Upload the chunks of `files` the server reported as missing, batched
+/// according to the server's per-request limits.
+pub async fn upload_missing_chunks(
+    client: &SentryClient,
+    options: &ChunkUploadOptions,
+    files: &[&ChunkedFile],
+    missing: &[String],
+) -> Result<()> {
+    let mut pending: Vec<(&str, &[u8])> = files
+        .iter()
+        .flat_map(|file| file.chunks())
+        .filter(|(checksum, _)| missing.iter().any(|m| m == checksum))
+        .collect();
+    // The same chunk may appear in several files; upload it once
+    pending.sort_by_key(|(checksum, _)| *checksum);
+    pending.dedup_by_key(|(checksum, _)| *checksum);
+
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let mut progress = Progress::new("Uploading chunks", pending.len());
+    let mut batch: Vec<(&str, &[u8])> = Vec::new();
+    let mut batch_size = 0;
+
+    for chunk in pending {
+        let full = batch.len() >= options.chunks_per_request.max(1)
+            || batch_size + chunk.1.len() > options.max_request_size;
+        if full && !batch.is_empty() {
+            client.upload_chunks(options, &batch).await?;
+            progress.inc(batch.len());
+            batch.clear();
+            batch_size = 0;
+        }
+        batch_size += chunk.1.len();
+        batch.push(chunk);
+    }
+
+    if !batch.is_empty() {
+        client.upload_chunks(options, &batch).await?;
+        progress.inc(batch.len());
+    }
+
+    progress.finish();
+    Ok(())
+}
@@ -0,0 +1,321 @@
+use crate::error::{Result, SentryCliError};
+use serde_json::{json, Map, Value};
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+const JS_EXTENSIONS: &[&str] = &["js", "mjs", "cjs"];
+const DEBUG_ID_COMMENT: &str = "//# debugId=";
+const SOURCE_MAPPING_URL_COMMENT: &str = "//# sourceMappingURL=";
+
+/// A file found while scanning, with the directory its URL is relative to
+#[derive(Debug)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub base: PathBuf,
+}
+
+impl SourceFile {
+    fn is_sourcemap(&self) -> bool {
+        self.path.extension().is_some_and(|e| e == "map")
+    }
+
+    /// Path relative to the scanned directory, with forward slashes
+    fn relative_path(&self) -> String {
+        let relative = self.path.strip_prefix(&self.base).unwrap_or(&self.path);
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+pub enum InjectOutcome {
+    Injected {
+        debug_id: Uuid,
+        sourcemap: Option<PathBuf>,
+    },
+    AlreadyInjected {
+        debug_id: String,
+    },
+}
+
+/// Collect JavaScript files (and, if requested, source maps) under the given paths
+pub fn collect_files(paths: &[PathBuf], include_sourcemaps: bool) -> Result<Vec<SourceFile>> {
+    let mut files = Vec::new();
+
+    for path in paths {
+        if !path.exists() {
+            return Err(SentryCliError::Validation(format!(
+                "Path does not exist: {}",
+                path.display()
+            )));
+        }
+
+        let base = if path.is_dir() {
+            path.clone()
+        } else {
+            path.parent().map(Path::to_path_buf).unwrap_or_default()
+        };
+
+        for entry in WalkDir::new(path).sort_by_file_name() {
+            let entry = entry.map_err(|e| SentryCliError::Io(e.into()))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Some(ext) = entry.path().extension().and_then(|e| e.to_str()) else {
+                continue;
+            };
+            if JS_EXTENSIONS.contains(&ext) || (include_sourcemaps && ext == "map") {
+                files.push(SourceFile {
+                    path: entry.into_path(),
+                    base: base.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Find an injected debug ID in minified source
+pub fn find_debug_id(source: &str) -> Option<&str> {
+    source
+        .lines()
+        .rev()
+        .find_map(|line| line.trim().strip_prefix(DEBUG_ID_COMMENT))
+        .map(str::trim)
+}
+
+/// Find the `sourceMappingURL` reference in minified source
+fn find_sourcemap_url(source: &str) -> Option<&str> {
+    source
+        .lines()
+        .rev()
+        .find_map(|line| line.trim().strip_prefix(SOURCE_MAPPING_URL_COMMENT))
+        .map(str::trim)
+}
+
+/// Derive a stable debug ID from the file contents, so rebuilding identical
+/// output produces the same ID
+fn debug_id_for(source: &[u8]) -> Uuid {
+    let hash = Sha1::digest(source);
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&hash[..16]);
+    // Mark as a version 4, RFC 4122 variant UUID
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    Uuid::from_bytes(bytes)
+}
+
+/// Locate the source map belonging to a minified file, either via its
+/// `sourceMappingURL` comment or a sibling `<file>.map`
+fn sourcemap_path(js_path: &Path, source: &str) -> Option<PathBuf> {
+    if let Some(url) = find_sourcemap_url(source) {
+        if url.starts_with("data:") || url.contains("://") {
+            return None;
+        }
+        let path = js_path.parent()?.join(url);
+        return path.exists().then_some(path);
+    }
+
+    let mut sibling = js_path.as_os_str().to_owned();
+    sibling.push(".map");
+    let sibling = PathBuf::from(sibling);
+    sibling.exists().then_some(sibling)
+}
+
+/// Inject a debug ID into a minified file and its source map.
+///
+/// A small snippet registering the debug ID is inserted as a new line at the
+/// top of the file (after a hashbang, if any), and a `//# debugId=` comment
+/// is appended. The source map gets a `debug_id` field and its mappings are
+/// shifted by the inserted line.
+pub fn inject(js_path: &Path, dry_run: bool) -> Result<InjectOutcome> {
+    let source = fs::read_to_string(js_path)?;
+
+    if let Some(debug_id) = find_debug_id(&source) {
+        return Ok(InjectOutcome::AlreadyInjected {
+            debug_id: debug_id.to_string(),
+        });
+    }
+
+    let debug_id = debug_id_for(source.as_bytes());
+    let sourcemap = sourcemap_path(js_path, &source);
+
+    // Keep a hashbang on the first line, where it must stay
+    let insert_line = usize::from(source.starts_with("#!"));
+    let (head, tail) = match insert_line {
+        0 => ("", source.as_str()),
+        _ => source.split_at(source.find('\n').map_or(source.len(), |i| i + 1)),
+    };
+
+    let mut injected = String::with_capacity(source.len() + 512);
+    injected.push_str(head);
+    if !head.is_empty() && !head.ends_with('\n') {
+        injected.push('\n');
+    }
+    injected.push_str(&debug_id_snippet(&debug_id));
+    injected.push('\n');
+    injected.push_str(tail);
+    if !injected.ends_with('\n') {
+        injected.push('\n');
+    }
+    injected.push_str(&format!("{}{}\n", DEBUG_ID_COMMENT, debug_id));
+
+    let updated_map = match &sourcemap {
+        Some(path) => Some(inject_sourcemap(path, &debug_id, insert_line)?),
+        None => None,
+    };
+
+    if !dry_run {
+        fs::write(js_path, injected)?;
+        if let (Some(path), Some(map)) = (&sourcemap, updated_map) {
+            fs::write(path, map)?;
+        }
+    }
+
+    Ok(InjectOutcome::Injected {
+        debug_id,
+        sourcemap,
+    })
+}
+
+fn debug_id_snippet(debug_id: &Uuid) -> String {
+    format!(
+        r#"!function(){{try{{var e="undefined"!=typeof window?window:"undefined"!=typeof global?global:"undefined"!=typeof globalThis?globalThis:"undefined"!=typeof self?self:{{}},n=(new e.Error).stack;n&&(e._sentryDebugIds=e._sentryDebugIds||{{}},e._sentryDebugIds[n]="{}")}}catch(e){{}}}}();"#,
+        debug_id
+    )
+}
+
+fn inject_sourcemap(path: &Path, debug_id: &Uuid, insert_line: usize) -> Result<String> {
+    let content = fs::read_to_string(path)?;
+    let mut map: Map<String, Value> = serde_json::from_str(&content).map_err(|e| {
+        SentryCliError::Validation(format!("Invalid source map {}: {}", path.display(), e))
+    })?;
+
+    if let Some(Value::String(mappings)) = map.get_mut("mappings") {
+        let mut lines: Vec<&str> = mappings.split(';').collect();
+        lines.insert(insert_line.min(lines.len()), "");
+        *mappings = lines.join(";");
+    }
+
+    // Index maps place sections at line offsets instead of carrying mappings
+    if let Some(Value::Array(sections)) = map.get_mut("sections") {
+        for section in sections {
+            if let Some(line) = section.pointer_mut("/offset/line") {
+                if let Some(n) = line.as_u64().filter(|n| *n as usize >= insert_line) {
+                    *line = json!(n + 1);
+                }
+            }
+        }
+    }
+
+    map.insert("debug_id".to_string(), json!(debug_id.to_string()));
+    map.insert("debugId".to_string(), json!(debug_id.to_string()));
+
+    Ok(serde_json::to_string(&map)?)
+}
+
+fn sourcemap_debug_id(content: &[u8]) -> Option<String> {
+    let map: Value = serde_json::from_slice(content).ok()?;
+    map.get("debug_id")
+        .or_else(|| map.get("debugId"))
+        .and_then(Value::as_str)
+        .map(String::from)
+}
+
+pub struct BundleOptions<'a> {
+    pub org: &'a str,
+    pub projects: &'a [String],
+    pub release: Option<&'a str>,
+    pub dist: Option<&'a str>,
+    pub url_prefix: &'a str,
+}
+
+/// Summary of a built artifact bundle
+pub struct Bundle {
+    pub data: Vec<u8>,
+    pub file_count: usize,
+    pub debug_ids: Vec<String>,
+}
+
+/// Build an artifact bundle: a zip of the files plus a `manifest.json`
+/// describing each file's URL, type and debug ID
+pub fn build_bundle(files: &[SourceFile], options: &BundleOptions) -> Result<Bundle> {
+    let url_prefix = options.url_prefix.trim_end_matches('/');
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let zip_options = SimpleFileOptions::default();
+    let mut manifest_files = Map::new();
+    let mut debug_ids = Vec::new();
+
+    for file in files {
+        let content = fs::read(&file.path)?;
+        let relative = file.relative_path();
+        let mut headers = Map::new();
+
+        let (file_type, debug_id) = if file.is_sourcemap() {
+            ("source_map", sourcemap_debug_id(&content))
+        } else {
+            let source = String::from_utf8_lossy(&content);
+            if let Some(url) = find_sourcemap_url(&source).filter(|u| !u.starts_with("data:")) {
+                headers.insert("sourcemap".to_string(), json!(url));
+            }
+            ("minified_source", find_debug_id(&source).map(String::from))
+        };
+
+        if let Some(debug_id) = debug_id {
+            headers.insert("debug-id".to_string(), json!(debug_id));
+            if !file.is_sourcemap() {
+                debug_ids.push(debug_id);
+            }
+        }
+
+        let entry_name = format!("files/_/_/{}", relative);
+        manifest_files.insert(
+            entry_name.clone(),
+            json!({
+                "url": format!("{}/{}", url_prefix, relative),
+                "type": file_type,
+                "headers": headers,
+            }),
+        );
+
+        zip.start_file(entry_name, zip_options).map_err(zip_error)?;
+        zip.write_all(&content)?;
+    }
+
+    let mut manifest = json!({
+        "org": options.org,
+        "projects": options.projects,
+        "files": manifest_files,
+    });
+    if let Some(release) = options.release {
+        manifest["release"] = json!(release);
+    }
+    if let Some(dist) = options.dist {
+        manifest["dist"] = json!(dist);
+    }
+
+    zip.start_file("manifest.json", zip_options)
+        .map_err(zip_error)?;
+    zip.write_all(serde_json::to_string(&manifest)?.as_bytes())?;
+
+    let data = zip.finish().map_err(zip_error)?.into_inner();
+
+    Ok(Bundle {
+        data,
+        file_count: files.len(),
+        debug_ids,
+    })
+}
+
+fn zip_error(e: zip::result::ZipError) -> SentryCliError {
+    SentryCliError::Io(std::io::Error::other(e))
+}
@@ -1,10 +1,116 @@
 use assert_cmd::Command;
 use predicates::prelude::*;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
 
 fn sentry_cli() -> Command {
     Command::new(assert_cmd::cargo::cargo_bin!("sentry"))
 }
 
+/// An issue as returned by the issues endpoints
+const ISSUE_JSON: &str = r#"{"id":"1","shortId":"WEB-1","title":"TypeError: x is undefined","status":"unresolved","level":"error","count":"3","userCount":1,"firstSeen":"2026-10-01T00:00:00Z","lastSeen":"2026-10-17T00:00:00Z","permalink":"https://sentry.io/issues/1/","project":{"id":"1","name":"web","slug":"web"},"assignedTo":null}"#;
+
+/// Status, extra headers and body of a stub response
+type StubResponse = (u16, Vec<(&'static str, String)>, String);
+
+/// A local HTTP server that answers every request with `respond(method,
+/// path)`, where the path includes the query. Requests are recorded as
+/// `"METHOD path"`, and their bodies apart.
+struct StubServer {
+    url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+    bodies: Arc<Mutex<Vec<String>>>,
+}
+
+impl StubServer {
+    fn start<F>(respond: F) -> Self
+    where
+        F: Fn(&str, &str) -> StubResponse + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&requests);
+        let body_log = Arc::clone(&bodies);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    if reader.read_line(&mut header).is_err() || header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = header.to_ascii_lowercase().strip_prefix("content-length:")
+                    {
+                        length = value.trim().parse().unwrap_or(0);
+                    }
+                }
+                let mut body = vec![0; length];
+                let _ = reader.read_exact(&mut body);
+
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or_default();
+                let path = parts.next().unwrap_or_default();
+                log.lock().unwrap().push(format!("{} {}", method, path));
+                body_log
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&body).into_owned());
+
+                let (status, headers, body) = respond(method, path);
+                let mut response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+                    status,
+                    body.len()
+                );
+                for (name, value) in headers {
+                    response.push_str(&format!("{}: {}\r\n", name, value));
+                }
+                response.push_str("\r\n");
+                response.push_str(&body);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        Self {
+            url,
+            requests,
+            bodies,
+        }
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn bodies(&self) -> Vec<String> {
+        self.bodies.lock().unwrap().clone()
+    }
+
+    /// The CLI pointed at this server, with a token and organization and
+    /// without any user configuration
+    fn cli(&self, config_dir: &std::path::Path) -> Command {
+        let mut cmd = sentry_cli();
+        cmd.env("XDG_CONFIG_HOME", config_dir)
+            .env_remove("SENTRY_PROFILE")
+            .env_remove("SENTRY_AUTH_TOKEN")
+            .env_remove("SENTRY_ORG")
+            .env_remove("SENTRY_PROJECT")
+            .args(["--server", &self.url, "--token", "t", "--org", "acme"]);
+        cmd
+    }
+}
+
 #[test]
 fn test_help() {
     sentry_cli()
@@ -101,3 +207,1625 @@ fn test_output_format_json() {
         .stdout(predicate::str::contains("json"))
         .stdout(predicate::str::contains("table"));
 }
+
+#[test]
+fn test_max_retries_flag() {
+    sentry_cli()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--max-retries"));
+}
+
+#[test]
+fn test_long_retry_after_is_not_waited_for() {
+    let server = StubServer::start(|_, _| {
+        (
+            429,
+            vec![("Retry-After", "86400".to_string())],
+            r#"{"detail":"Slow down"}"#.to_string(),
+        )
+    });
+    let dir = std::env::temp_dir().join(format!("sentry-cli-retry-{}", std::process::id()));
+
+    server
+        .cli(&dir)
+        .args(["issues", "view", "1"])
+        .timeout(std::time::Duration::from_secs(20))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Retry after 86400 seconds"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_transient_failure_is_retried() {
+    let attempts = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&attempts);
+    let server = StubServer::start(move |_, _| {
+        let mut attempts = counter.lock().unwrap();
+        *attempts += 1;
+        if *attempts == 1 {
+            (503, vec![("Retry-After", "0".to_string())], String::new())
+        } else {
+            (200, Vec::new(), ISSUE_JSON.to_string())
+        }
+    });
+    let dir = std::env::temp_dir().join(format!("sentry-cli-retried-{}", std::process::id()));
+
+    server
+        .cli(&dir)
+        .args(["issues", "view", "1", "-O", "json", "--jq", ".shortId"])
+        .assert()
+        .success()
+        .stdout("WEB-1\n");
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn test_invalid_max_retries_env() {
+    sentry_cli()
+        .args([
+            "--org",
+            "test-org",
+            "--token",
+            "fake-token",
+            "issues",
+            "list",
+        ])
+        .env("SENTRY_MAX_RETRIES", "lots")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("SENTRY_MAX_RETRIES"));
+}
+
+#[test]
+fn test_events_help() {
+    sentry_cli()
+        .args(["events", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("list"))
+        .stdout(predicate::str::contains("view"));
+}
+
+#[test]
+fn test_events_view_help() {
+    sentry_cli()
+        .args(["events", "view", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--in-app"))
+        .stdout(predicate::str::contains("--breadcrumbs"))
+        .stdout(predicate::str::contains("latest"));
+}
+
+#[test]
+fn test_releases_help() {
+    sentry_cli()
+        .args(["releases", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("new"))
+        .stdout(predicate::str::contains("finalize"))
+        .stdout(predicate::str::contains("set-commits"))
+        .stdout(predicate::str::contains("delete"));
+}
+
+#[test]
+fn test_releases_new_requires_project() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-no-project-{}", std::process::id()));
+    sentry_cli()
+        .env("XDG_CONFIG_HOME", &dir)
+        .env_remove("SENTRY_PROFILE")
+        .env_remove("SENTRY_PROJECT")
+        .args(["--token", "fake-token", "releases", "new", "1.0.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--project"))
+        .stderr(predicate::str::contains("default_project"));
+}
+
+#[test]
+fn test_issues_list_all_projects_conflicts_with_project() {
+    sentry_cli()
+        .args(["issues", "list", "--all-projects", "--project", "web"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_issues_list_help_shows_max_results() {
+    sentry_cli()
+        .args(["issues", "list", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--max-results"))
+        .stdout(predicate::str::contains("--limit"));
+}
+
+#[test]
+fn test_issues_list_cursor_conflicts_with_watch() {
+    sentry_cli()
+        .args(["issues", "list", "--watch", "--cursor", "0:25:0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_issues_list_watch_options() {
+    sentry_cli()
+        .args(["issues", "list", "--interval", "10s"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--watch"));
+
+    sentry_cli()
+        .args(["issues", "list", "--watch", "--interval", "5x"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid unit"));
+
+    sentry_cli()
+        .args([
+            "issues",
+            "list",
+            "--watch",
+            "--interval",
+            "18446744073709551615h",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("too long"));
+}
+
+#[test]
+fn test_issues_triage_requires_terminal() {
+    sentry_cli()
+        .args(["--org", "test-org", "--token", "fake-token"])
+        .args(["issues", "triage", "--all-projects"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("interactive terminal"));
+}
+
+#[test]
+fn test_releases_set_commits_invalid_spec() {
+    sentry_cli()
+        .args(["--org", "test-org", "--token", "fake-token"])
+        .args([
+            "releases",
+            "set-commits",
+            "1.0.0",
+            "--commit",
+            "no-revision",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid commit spec"));
+}
+
+/// A git repository with `count` commits and a GitHub remote. Returns the
+/// directory and the commit IDs, oldest first.
+fn git_repo(name: &str, count: usize) -> (std::path::PathBuf, Vec<String>) {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .current_dir(&dir)
+            .args(["-c", "user.name=Jane", "-c", "user.email=jane@example.com"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    };
+
+    git(&["init", "-q"]);
+    git(&["remote", "add", "origin", "git@github.com:acme/web.git"]);
+    let commits = (0..count)
+        .map(|i| {
+            git(&[
+                "commit",
+                "-q",
+                "--allow-empty",
+                "-m",
+                &format!("Change {}", i),
+            ]);
+            git(&["rev-parse", "HEAD"])
+        })
+        .collect();
+    (dir, commits)
+}
+
+/// Number of commits sent by the last `set-commits` request
+fn sent_commits(server: &StubServer) -> usize {
+    let body: serde_json::Value = serde_json::from_str(server.bodies().last().unwrap()).unwrap();
+    body["commits"].as_array().unwrap().len()
+}
+
+#[test]
+fn test_releases_set_commits_auto() {
+    let (repo, commits) = git_repo("set-commits", 26);
+    let previous = Arc::new(Mutex::new(serde_json::Value::Null));
+    let last_commit = Arc::clone(&previous);
+    let server = StubServer::start(move |method, _| match method {
+        "GET" => (
+            200,
+            Vec::new(),
+            serde_json::json!({ "lastCommit": *last_commit.lock().unwrap() }).to_string(),
+        ),
+        _ => (
+            200,
+            Vec::new(),
+            r#"{"version":"2.0","dateCreated":"2026-10-17T00:00:00Z","commitCount":25}"#
+                .to_string(),
+        ),
+    });
+    let config = repo.join(".config");
+    let set_commits = || {
+        let mut cmd = server.cli(&config);
+        cmd.current_dir(&repo)
+            .args(["releases", "set-commits", "2.0", "--auto", "-O", "json"]);
+        cmd
+    };
+
+    // Every commit since the previous release, beyond --initial-depth
+    *previous.lock().unwrap() = serde_json::json!({ "id": commits[0] });
+    set_commits().assert().success();
+    assert_eq!(sent_commits(&server), 25);
+
+    // Without a previous release, --initial-depth commits
+    *previous.lock().unwrap() = serde_json::Value::Null;
+    set_commits().assert().success();
+    assert_eq!(sent_commits(&server), 20);
+
+    // A previous commit missing locally, as in a shallow clone
+    *previous.lock().unwrap() =
+        serde_json::json!({ "id": "0123456789abcdef0123456789abcdef01234567" });
+    set_commits()
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not in the local history"));
+
+    std::fs::remove_dir_all(&repo).unwrap();
+}
+
+#[test]
+fn test_releases_delete_json() {
+    let server = StubServer::start(|_, _| (204, Vec::new(), String::new()));
+    let dir =
+        std::env::temp_dir().join(format!("sentry-cli-release-delete-{}", std::process::id()));
+
+    let output = server
+        .cli(&dir)
+        .args(["releases", "delete", "1.0.0", "--confirm", "-O", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({ "version": "1.0.0", "deleted": true, "cancelled": false })
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    let output = server
+        .cli(&dir)
+        .args(["releases", "delete", "1.0.0", "-O", "json"])
+        .write_stdin("n\n")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(result["cancelled"], true);
+    assert_eq!(server.requests().len(), 1);
+
+    server
+        .cli(&dir)
+        .args([
+            "releases",
+            "delete",
+            "1.0.0",
+            "--confirm",
+            "--jq",
+            ".deleted",
+        ])
+        .assert()
+        .success()
+        .stdout("true\n");
+}
+
+#[test]
+fn test_projects_delete_json() {
+    let server = StubServer::start(|_, _| (204, Vec::new(), String::new()));
+    let dir =
+        std::env::temp_dir().join(format!("sentry-cli-project-delete-{}", std::process::id()));
+
+    let output = server
+        .cli(&dir)
+        .args(["projects", "delete", "backend", "--confirm", "-O", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({ "project": "backend", "deleted": true, "cancelled": false })
+    );
+    assert_eq!(
+        server.requests(),
+        vec!["DELETE /api/0/projects/acme/backend/".to_string()]
+    );
+
+    let output = server
+        .cli(&dir)
+        .args(["projects", "delete", "backend", "-O", "json"])
+        .write_stdin("n\n")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(result["cancelled"], true);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_releases_deploys_new_help() {
+    sentry_cli()
+        .args(["releases", "deploys", "1.0.0", "new", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--env"))
+        .stdout(predicate::str::contains("--started"))
+        .stdout(predicate::str::contains("--finished"));
+}
+
+#[test]
+fn test_releases_deploys_new_requires_env() {
+    sentry_cli()
+        .args(["releases", "deploys", "1.0.0", "new"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--env"));
+}
+
+#[test]
+fn test_releases_deploys() {
+    const DEPLOY_JSON: &str = r#"{"id":"7","environment":"production","name":"build-512","dateStarted":"2026-10-17T10:00:00Z","dateFinished":"2026-10-17T10:05:00Z"}"#;
+    let server = StubServer::start(|method, _| match method {
+        "POST" => (201, Vec::new(), DEPLOY_JSON.to_string()),
+        _ => (200, Vec::new(), format!("[{}]", DEPLOY_JSON)),
+    });
+    let dir = std::env::temp_dir().join(format!("sentry-cli-deploys-{}", std::process::id()));
+
+    server
+        .cli(&dir)
+        .args([
+            "releases",
+            "deploys",
+            "1.0.0",
+            "new",
+            "--env",
+            "production",
+            "--started",
+            "yesterday",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --started date"));
+    assert!(server.requests().is_empty());
+
+    let output = server
+        .cli(&dir)
+        .args([
+            "releases",
+            "deploys",
+            "1.0.0",
+            "new",
+            "--env",
+            "production",
+            "--name",
+            "build-512",
+            "--started",
+            "2026-10-17T12:00:00+02:00",
+            "-O",
+            "json",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(result["id"], "7");
+    assert_eq!(result["environment"], "production");
+    let body: serde_json::Value = serde_json::from_str(&server.bodies()[0]).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "environment": "production",
+            "name": "build-512",
+            "dateStarted": "2026-10-17T10:00:00Z",
+        })
+    );
+
+    server
+        .cli(&dir)
+        .args(["releases", "deploys", "1.0.0", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("production"))
+        .stdout(predicate::str::contains("build-512"));
+    assert_eq!(
+        server.requests(),
+        [
+            "POST /api/0/organizations/acme/releases/1.0.0/deploys/",
+            "GET /api/0/organizations/acme/releases/1.0.0/deploys/",
+        ]
+    );
+}
+
+#[test]
+fn test_sourcemaps_help() {
+    sentry_cli()
+        .args(["sourcemaps", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("inject"))
+        .stdout(predicate::str::contains("upload"));
+}
+
+#[test]
+fn test_sourcemaps_inject() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-inject-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let js = dir.join("app.min.js");
+    let map = dir.join("app.min.js.map");
+    std::fs::write(
+        &js,
+        "console.log(1);\n//# sourceMappingURL=app.min.js.map\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &map,
+        r#"{"version":3,"sources":["app.js"],"names":[],"mappings":"AAAA"}"#,
+    )
+    .unwrap();
+
+    sentry_cli()
+        .args(["sourcemaps", "inject"])
+        .arg(&dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Injected debug IDs into 1 of 1 file(s)",
+        ));
+
+    let js_content = std::fs::read_to_string(&js).unwrap();
+    let map_content = std::fs::read_to_string(&map).unwrap();
+    assert!(js_content.contains("//# debugId="));
+    assert!(map_content.contains("\"debug_id\""));
+    assert!(map_content.contains("\";AAAA\""));
+
+    // Running again leaves already injected files untouched
+    sentry_cli()
+        .args(["sourcemaps", "inject"])
+        .arg(&dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 of 1 file(s)"));
+    assert_eq!(std::fs::read_to_string(&js).unwrap(), js_content);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_debug_files_help() {
+    sentry_cli()
+        .args(["debug-files", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("check"))
+        .stdout(predicate::str::contains("find"))
+        .stdout(predicate::str::contains("upload"));
+}
+
+#[test]
+fn test_debug_files_check_breakpad() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-dif-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let sym = dir.join("libfoo.sym");
+    std::fs::write(
+        &sym,
+        "MODULE Linux x86_64 0123456789ABCDEF0123456789ABCDEF1 libfoo.so\n",
+    )
+    .unwrap();
+    let other = dir.join("notes.txt");
+    std::fs::write(&other, "not a debug file\n").unwrap();
+
+    sentry_cli()
+        .args(["debug-files", "check"])
+        .arg(&sym)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("breakpad"))
+        .stdout(predicate::str::contains(
+            "01234567-89ab-cdef-0123-456789abcdef-1",
+        ));
+
+    sentry_cli()
+        .args(["debug-files", "check"])
+        .arg(&other)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "not a supported debug information file",
+        ));
+
+    sentry_cli()
+        .args([
+            "debug-files",
+            "find",
+            "0123456789ABCDEF0123456789ABCDEF1",
+            "--path",
+        ])
+        .arg(&dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("libfoo.sym"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_debug_files_split_dwarf() {
+    let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    // DWARF 5 keeps the DWO ID in the unit header, DWARF 4 in an attribute
+    let cases = [
+        ("add.dwo", "3bbb69d4-9ea2-24be-0000-000000000000"),
+        ("add-dwarf4.dwo", "ea1b8720-ee5b-7ee8-0000-000000000000"),
+    ];
+    for (file, debug_id) in cases {
+        sentry_cli()
+            .args(["debug-files", "check", "-O", "json"])
+            .arg(fixtures.join(file))
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"kind\": \"split_dwarf\""))
+            .stdout(predicate::str::contains(debug_id));
+    }
+
+    sentry_cli()
+        .args([
+            "debug-files",
+            "find",
+            "3bbb69d49ea224be0000000000000000",
+            "--path",
+        ])
+        .arg(&fixtures)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("add.dwo"));
+}
+
+/// A Breakpad symbol file to print in the output formats, in a new temporary
+/// directory. Returns the directory, for removal, and the file.
+fn sym_fixture(name: &str) -> (std::path::PathBuf, std::path::PathBuf) {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let sym = dir.join("libbar.sym");
+    std::fs::write(
+        &sym,
+        "MODULE Linux x86_64 0123456789ABCDEF0123456789ABCDEF1 libbar.so\n",
+    )
+    .unwrap();
+    (dir, sym)
+}
+
+#[test]
+fn test_output_formats() {
+    let (dir, sym) = sym_fixture("formats");
+
+    let cases = [
+        ("csv", "kind,arch,"),
+        ("tsv", "breakpad\tx86_64\t"),
+        ("ndjson", "{\"path\":"),
+        ("yaml", "kind: breakpad\n"),
+        ("markdown", "| --- |"),
+    ];
+    for (format, expected) in cases {
+        sentry_cli()
+            .args(["debug-files", "check", "--output", format])
+            .arg(&sym)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(expected));
+    }
+
+    // Compact output is one line per item, without a table around it
+    sentry_cli()
+        .args(["debug-files", "check", "--output", "compact"])
+        .arg(&sym)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("libbar.sym"))
+        .stdout(predicate::str::contains("\n").count(1));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_output_template() {
+    let (dir, sym) = sym_fixture("template");
+
+    // Fields are available under their snake_case names as well
+    sentry_cli()
+        .args(["debug-files", "check", "--template"])
+        .arg("{{kind}} {{debug_id}} {{truncate arch 4}}")
+        .arg(&sym)
+        .assert()
+        .success()
+        .stdout("breakpad 01234567-89ab-cdef-0123-456789abcdef-1 x...\n");
+
+    let template = dir.join("check.hbs");
+    std::fs::write(&template, "{{#each features}}{{this}}\n{{/each}}").unwrap();
+    sentry_cli()
+        .args(["debug-files", "check", "--template-file"])
+        .arg(&template)
+        .arg(&sym)
+        .assert()
+        .success()
+        .stdout("symtab\nunwind\n");
+
+    sentry_cli()
+        .args(["debug-files", "check", "--template", "{{#if}"])
+        .arg(&sym)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Template error"));
+
+    sentry_cli()
+        .args([
+            "debug-files",
+            "check",
+            "--template",
+            "{{color kind \"nocolor\"}}",
+        ])
+        .arg(&sym)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown color: nocolor"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_output_jq() {
+    let (dir, sym) = sym_fixture("jq");
+
+    // Strings are printed as plain text, anything else as JSON
+    let cases = [
+        (".kind", "breakpad\n"),
+        (".features | length", "2\n"),
+        (".features[]", "symtab\nunwind\n"),
+    ];
+    for (expr, expected) in cases {
+        sentry_cli()
+            .args(["debug-files", "check", "--jq", expr])
+            .arg(&sym)
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+
+    sentry_cli()
+        .args(["debug-files", "check", "--jq", ".["])
+        .arg(&sym)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid jq expression"));
+
+    sentry_cli()
+        .args(["debug-files", "check", "--jq", ".kind[0]"])
+        .arg(&sym)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("jq filter failed"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// A server with issues 1 to 3 in pages of two, linked by cursors as the
+/// Sentry API does. The title of issue 2 has a comma and quotes in it.
+fn issues_server() -> StubServer {
+    StubServer::start(|_, path| {
+        let last = path.contains("cursor=0%3A2%3A0");
+        let ids: &[u32] = if last { &[3] } else { &[1, 2] };
+        let issues: Vec<serde_json::Value> = ids
+            .iter()
+            .map(|id| {
+                let mut issue: serde_json::Value = serde_json::from_str(ISSUE_JSON).unwrap();
+                issue["id"] = id.to_string().into();
+                issue["shortId"] = format!("WEB-{}", id).into();
+                if *id == 2 {
+                    issue["title"] = r#"Error: "x", y"#.into();
+                }
+                issue
+            })
+            .collect();
+        let link = format!(
+            r#"<{}>; rel="previous"; results="false"; cursor="0:0:1", <{}>; rel="next"; results="{}"; cursor="0:2:0""#,
+            path, path, !last
+        );
+        (
+            200,
+            vec![("Link", link)],
+            serde_json::to_string(&issues).unwrap(),
+        )
+    })
+}
+
+#[test]
+fn test_issues_list_streams_pages() {
+    let server = issues_server();
+    let dir = std::env::temp_dir().join(format!("sentry-cli-pages-{}", std::process::id()));
+
+    server
+        .cli(&dir)
+        .args([
+            "issues",
+            "list",
+            "--all",
+            "-O",
+            "ndjson",
+            "--jq",
+            ".issues[].shortId",
+        ])
+        .assert()
+        .success()
+        .stdout("WEB-1\nWEB-2\nWEB-3\n");
+    assert_eq!(server.requests().len(), 2);
+
+    // Line formats print each page as it arrives
+    server
+        .cli(&dir)
+        .args(["issues", "list", "--all", "-O", "ndjson"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\n").count(3));
+
+    // Pages past --max-results are never requested
+    server
+        .cli(&dir)
+        .args(["issues", "list", "--max-results", "1", "-O", "ndjson"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\n").count(1));
+    assert_eq!(server.requests().len(), 5);
+}
+
+#[test]
+fn test_issues_list_envelope() {
+    let server = issues_server();
+    let dir = std::env::temp_dir().join(format!("sentry-cli-envelope-{}", std::process::id()));
+
+    let output = server
+        .cli(&dir)
+        .args(["issues", "list", "-O", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let list: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(list["issues"].as_array().unwrap().len(), 2);
+    assert_eq!(list["next_cursor"], "0:2:0");
+    assert_eq!(list["prev_cursor"], serde_json::Value::Null);
+
+    let output = server
+        .cli(&dir)
+        .args(["issues", "list", "--all", "-O", "yaml"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let list: serde_json::Value = serde_yaml::from_slice(&output).unwrap();
+    assert_eq!(list["issues"].as_array().unwrap().len(), 3);
+    assert_eq!(list["issues"][2]["shortId"], "WEB-3");
+    assert_eq!(list["next_cursor"], serde_json::Value::Null);
+
+    // --jq sees the same document
+    server
+        .cli(&dir)
+        .args(["issues", "list", "--jq", ".next_cursor, (.issues | length)"])
+        .assert()
+        .success()
+        .stdout("0:2:0\n2\n");
+}
+
+#[test]
+fn test_issues_list_csv_fields() {
+    let server = issues_server();
+    let dir = std::env::temp_dir().join(format!("sentry-cli-csv-fields-{}", std::process::id()));
+
+    server
+        .cli(&dir)
+        .args(["issues", "list", "-O", "csv", "--fields", "short_id,title,project"])
+        .assert()
+        .success()
+        .stdout("shortId,title,project\nWEB-1,TypeError: x is undefined,web\nWEB-2,\"Error: \"\"x\"\", y\",web\n");
+
+    // The issue_fields default applies too
+    std::fs::create_dir_all(dir.join("sentry-cli")).unwrap();
+    std::fs::write(
+        dir.join("sentry-cli/config.toml"),
+        "issue_fields = \"id,level\"\n",
+    )
+    .unwrap();
+    server
+        .cli(&dir)
+        .args(["issues", "list", "-O", "tsv"])
+        .assert()
+        .success()
+        .stdout("id\tlevel\n1\terror\n2\terror\n");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_issues_list_jq() {
+    let server = issues_server();
+    let dir = std::env::temp_dir().join(format!("sentry-cli-list-jq-{}", std::process::id()));
+
+    // The input is the JSON document, with the issues in a field
+    server
+        .cli(&dir)
+        .args(["issues", "list", "--jq", ".issues[].shortId"])
+        .assert()
+        .success()
+        .stdout("WEB-1\nWEB-2\n");
+
+    server
+        .cli(&dir)
+        .args(["issues", "list", "--jq", ".[].shortId"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("jq filter failed"));
+}
+
+#[test]
+fn test_issues_list_csv_quoting() {
+    let server = issues_server();
+    let dir = std::env::temp_dir().join(format!("sentry-cli-csv-{}", std::process::id()));
+
+    server
+        .cli(&dir)
+        .args(["issues", "list", "--all", "-O", "csv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("id,shortId,title,"))
+        .stdout(predicate::str::contains(r#"2,WEB-2,"Error: ""x"", y","#))
+        .stdout(predicate::str::contains("\n").count(4));
+}
+
+#[test]
+fn test_delete_prompt_on_stderr() {
+    sentry_cli()
+        .args(["--token", "t", "-o", "acme", "releases", "delete", "1.0.0"])
+        .write_stdin("n\n")
+        .assert()
+        .success()
+        .stdout("Cancelled.\n")
+        .stderr(predicate::str::contains("delete release 1.0.0? [y/N]"));
+}
+
+#[test]
+fn test_issues_delete_cancelled_json() {
+    let output = sentry_cli()
+        .args([
+            "--token", "t", "-o", "acme", "issues", "delete", "1", "-O", "json",
+        ])
+        .write_stdin("n\n")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(result["action"], "delete");
+    assert_eq!(result["cancelled"], true);
+    assert_eq!(result["ids"], serde_json::json!([]));
+}
+
+#[test]
+fn test_issues_resolve_fetch_error_is_not_fatal() {
+    let server = StubServer::start(|method, path| match method {
+        "PUT" => (204, Vec::new(), String::new()),
+        _ if path.ends_with("/issues/1/") => (200, Vec::new(), ISSUE_JSON.to_string()),
+        _ => (404, Vec::new(), r#"{"detail":"Not found"}"#.to_string()),
+    });
+    let dir = std::env::temp_dir().join(format!("sentry-cli-fetch-{}", std::process::id()));
+
+    let output = server
+        .cli(&dir)
+        .args(["issues", "resolve", "1", "2", "-O", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(result["ids"], serde_json::json!(["1", "2"]));
+    assert_eq!(result["issues"][0]["shortId"], "WEB-1");
+    assert_eq!(result["fetch_errors"][0]["id"], "2");
+}
+
+#[test]
+fn test_monitors_help() {
+    sentry_cli()
+        .args(["monitors", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("run"))
+        .stdout(predicate::str::contains("checkin"));
+}
+
+#[test]
+fn test_monitors_run_requires_command() {
+    sentry_cli()
+        .args(["monitors", "run", "nightly-backup"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("required"));
+}
+
+#[test]
+fn test_monitors_run_reports_failed_checkin() {
+    let server = StubServer::start(|method, _| match method {
+        "POST" => (
+            201,
+            Vec::new(),
+            r#"{"id":"c1","status":"in_progress","dateCreated":"2024-01-01T00:00:00Z"}"#
+                .to_string(),
+        ),
+        _ => (
+            400,
+            Vec::new(),
+            r#"{"detail":"Invalid check-in"}"#.to_string(),
+        ),
+    });
+    let dir = std::env::temp_dir().join(format!("sentry-cli-monitors-run-{}", std::process::id()));
+
+    let output = server
+        .cli(&dir)
+        .args(["monitors", "run", "nightly", "-O", "json", "--", "true"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let result: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(result["checkInId"], "c1");
+    assert_eq!(result["status"], "ok");
+    assert_eq!(result["exitCode"], 0);
+    assert_eq!(result["checkInSent"], false);
+    assert_eq!(
+        server.requests(),
+        [
+            "POST /api/0/organizations/acme/monitors/nightly/checkins/",
+            "PUT /api/0/organizations/acme/monitors/nightly/checkins/c1/",
+        ]
+    );
+
+    server
+        .cli(&dir)
+        .args(["monitors", "run", "nightly", "--", "false"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "error check-in to nightly failed.",
+        ));
+}
+
+#[test]
+fn test_monitors_config_requires_schedule() {
+    sentry_cli()
+        .args([
+            "monitors",
+            "checkin",
+            "nightly-backup",
+            "--status",
+            "ok",
+            "--max-runtime",
+            "30",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--schedule"));
+}
+
+#[test]
+fn test_send_event_invalid_dsn() {
+    sentry_cli()
+        .args([
+            "send-event",
+            "-m",
+            "hello",
+            "--dsn",
+            "https://o0.ingest.sentry.io/42",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid DSN: missing public key"));
+}
+
+#[test]
+fn test_send_event_invalid_tag() {
+    sentry_cli()
+        .args(["send-event", "-m", "hello", "--tag", "novalue"])
+        .args(["--dsn", "https://key@o0.ingest.sentry.io/42"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Expected KEY:VALUE"));
+}
+
+#[test]
+fn test_send_envelope_invalid_file() {
+    let path = std::env::temp_dir().join(format!("sentry-cli-{}.envelope", std::process::id()));
+    std::fs::write(&path, "not an envelope\n").unwrap();
+
+    sentry_cli()
+        .arg("send-envelope")
+        .arg(&path)
+        .args(["--dsn", "https://key@o0.ingest.sentry.io/42"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid envelope"));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_projects_help() {
+    sentry_cli()
+        .args(["projects", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("create"))
+        .stdout(predicate::str::contains("keys"));
+}
+
+#[test]
+fn test_projects_create_requires_team() {
+    sentry_cli()
+        .args(["projects", "create", "backend"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--team"));
+}
+
+#[test]
+fn test_orgs_help() {
+    sentry_cli()
+        .args(["orgs", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("list"))
+        .stdout(predicate::str::contains("view"));
+}
+
+#[test]
+fn test_teams_list_requires_org() {
+    sentry_cli()
+        .args(["--token", "fake-token", "teams", "list"])
+        .env_remove("SENTRY_ORG")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("organization"));
+}
+
+#[test]
+fn test_orgs_and_teams() {
+    let server = StubServer::start(|method, path| match (method, path) {
+        ("GET", "/api/0/organizations/") => (
+            200,
+            Vec::new(),
+            r#"[{"id":"1","slug":"acme","name":"Acme","dateCreated":"2024-01-01T00:00:00Z"}]"#
+                .to_string(),
+        ),
+        ("GET", "/api/0/organizations/acme/") => (
+            200,
+            Vec::new(),
+            r#"{"id":"1","slug":"acme","name":"Acme","dateCreated":"2024-01-01T00:00:00Z"}"#
+                .to_string(),
+        ),
+        ("POST", "/api/0/organizations/acme/teams/") => (
+            201,
+            Vec::new(),
+            r#"{"id":"5","slug":"backend","name":"Backend","dateCreated":"2024-01-01T00:00:00Z"}"#
+                .to_string(),
+        ),
+        ("GET", "/api/0/teams/acme/backend/members/") => (
+            200,
+            Vec::new(),
+            r#"[{"id":"9","email":"jo@example.com","teamRole":"admin"}]"#.to_string(),
+        ),
+        _ => (404, Vec::new(), r#"{"detail":"Not found"}"#.to_string()),
+    });
+    let dir = std::env::temp_dir().join(format!("sentry-cli-orgs-{}", std::process::id()));
+
+    server
+        .cli(&dir)
+        .args(["orgs", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("acme"));
+
+    let output = server
+        .cli(&dir)
+        .args(["orgs", "view", "-O", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let org: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(org["name"], "Acme");
+
+    server
+        .cli(&dir)
+        .args(["teams", "create", "backend", "--name", "Backend"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created team backend."));
+    let body: serde_json::Value = serde_json::from_str(&server.bodies()[2]).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({ "slug": "backend", "name": "Backend" })
+    );
+
+    let output = server
+        .cli(&dir)
+        .args(["teams", "members", "backend", "-O", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let members: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(members[0]["email"], "jo@example.com");
+
+    server
+        .cli(&dir)
+        .args(["orgs", "view", "missing"])
+        .assert()
+        .failure();
+    assert_eq!(server.requests().len(), 5);
+}
+
+#[test]
+fn test_config_profiles() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-profiles-{}", std::process::id()));
+    let cli = || {
+        let mut cmd = sentry_cli();
+        cmd.env("XDG_CONFIG_HOME", &dir)
+            .env_remove("SENTRY_PROFILE");
+        cmd
+    };
+
+    cli()
+        .args(["config", "profiles", "add", "onprem"])
+        .args(["--server-url", "https://sentry.example.com"])
+        .args(["--default-org", "acme"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["config", "profiles", "use", "onprem"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["config", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Profile:     onprem"))
+        .stdout(predicate::str::contains("https://sentry.example.com"));
+
+    cli()
+        .args(["--profile", "missing", "config", "show"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Profile 'missing' not found"));
+
+    cli()
+        .args(["config", "profiles", "remove", "onprem"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["config", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Profile:     default"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_profile_token_not_inherited() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-inherit-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("sentry-cli")).unwrap();
+    std::fs::write(
+        dir.join("sentry-cli/config.toml"),
+        "auth_token = \"sntrys_toplevel\"\n\n[profiles.onprem]\nserver_url = \"https://sentry.example.com\"\n",
+    )
+    .unwrap();
+    let cli = || {
+        let mut cmd = sentry_cli();
+        cmd.env("XDG_CONFIG_HOME", &dir)
+            .env_remove("SENTRY_PROFILE")
+            .env_remove("SENTRY_AUTH_TOKEN");
+        cmd
+    };
+
+    cli()
+        .args(["--profile", "onprem", "config", "get", "auth_token"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("auth_token is not set"));
+
+    // set writes to the profile selected by SENTRY_PROFILE
+    cli()
+        .env("SENTRY_PROFILE", "onprem")
+        .args(["config", "set", "default_org", "acme"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["--profile", "onprem", "config", "get", "default_org"])
+        .assert()
+        .success()
+        .stdout("acme\n");
+
+    cli()
+        .args(["config", "get", "default_org"])
+        .assert()
+        .failure();
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_token_storage() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-secrets-{}", std::process::id()));
+    let cli = || {
+        let mut cmd = sentry_cli();
+        cmd.env("XDG_CONFIG_HOME", &dir)
+            .env_remove("SENTRY_PROFILE")
+            .env_remove("SENTRY_AUTH_TOKEN");
+        cmd
+    };
+
+    cli()
+        .args(["config", "set", "token_storage", "file"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["config", "set", "auth_token", "sntrys_secret"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("credentials.toml"));
+
+    let config = std::fs::read_to_string(dir.join("sentry-cli/config.toml")).unwrap();
+    assert!(!config.contains("sntrys_secret"));
+
+    cli()
+        .args(["config", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("credentials.toml"));
+
+    // Another profile only reads its own entry
+    cli()
+        .args(["config", "profiles", "add", "other"])
+        .assert()
+        .success();
+
+    cli()
+        .args([
+            "--profile",
+            "other",
+            "config",
+            "set",
+            "token_storage",
+            "file",
+        ])
+        .assert()
+        .success();
+
+    cli()
+        .args(["--profile", "other", "config", "get", "auth_token"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("auth_token is not set"));
+
+    cli()
+        .args(["config", "get", "auth_token"])
+        .assert()
+        .success()
+        .stdout("sntrys_secret\n");
+
+    cli()
+        .args(["config", "set", "token_storage", "vault"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid token_storage"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_login_help() {
+    sentry_cli()
+        .args(["login", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--device"))
+        .stdout(predicate::str::contains("--client-id"));
+}
+
+#[test]
+fn test_login_requires_token() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-login-{}", std::process::id()));
+    sentry_cli()
+        .env("XDG_CONFIG_HOME", &dir)
+        .env_remove("SENTRY_PROFILE")
+        .args(["--server", "http://127.0.0.1:9", "login", "--no-browser"])
+        .write_stdin("\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("auth-tokens"))
+        .stderr(predicate::str::contains("Auth token is required"));
+}
+
+#[test]
+fn test_auth_status_requires_token() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-status-{}", std::process::id()));
+    sentry_cli()
+        .env("XDG_CONFIG_HOME", &dir)
+        .env_remove("SENTRY_PROFILE")
+        .env_remove("SENTRY_AUTH_TOKEN")
+        .args(["auth", "status"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("auth token"));
+}
+
+#[test]
+fn test_config_local_files() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-local-{}", std::process::id()));
+    let nested = dir.join("project/service");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(
+        dir.join(".sentryclirc"),
+        "[defaults]\norg = outer\nproject = web\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("project/sentry.toml"), "default_org = \"inner\"\n").unwrap();
+
+    sentry_cli()
+        .current_dir(&nested)
+        .env("XDG_CONFIG_HOME", dir.join("config"))
+        .env_remove("SENTRY_PROFILE")
+        .env_remove("SENTRY_ORG")
+        .args(["config", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("default_org:     inner"))
+        .stdout(predicate::str::contains("sentry.toml)"))
+        .stdout(predicate::str::contains("default_project: web"))
+        .stdout(predicate::str::contains(".sentryclirc)"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_local_server_url_needs_token() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-local-url-{}", std::process::id()));
+    let nested = dir.join("project");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(
+        dir.join("sentry.toml"),
+        "server_url = \"https://evil.example.com\"\ntoken_storage = \"file\"\n",
+    )
+    .unwrap();
+    std::fs::write(nested.join(".sentryclirc"), "[defaults]\norg = acme\n").unwrap();
+    let cli = || {
+        let mut cmd = sentry_cli();
+        cmd.env("XDG_CONFIG_HOME", dir.join("config"))
+            .env_remove("SENTRY_PROFILE")
+            .env_remove("SENTRY_SERVER_URL")
+            .env_remove("SENTRY_AUTH_TOKEN");
+        cmd
+    };
+
+    cli()
+        .current_dir(&nested)
+        .args(["config", "get", "server_url"])
+        .assert()
+        .success()
+        .stdout("https://sentry.io\n")
+        .stderr(predicate::str::contains("ignoring server_url"))
+        .stderr(predicate::str::contains("ignoring token_storage"));
+
+    // With its own token, the file may choose the server
+    std::fs::write(
+        dir.join("sentry.toml"),
+        "server_url = \"https://sentry.example.com\"\nauth_token = \"sntrys_project\"\n",
+    )
+    .unwrap();
+
+    cli()
+        .current_dir(&nested)
+        .args(["config", "get", "server_url"])
+        .assert()
+        .success()
+        .stdout("https://sentry.example.com\n")
+        .stderr("");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_local_server_url_not_used_with_other_token() {
+    let trusted = StubServer::start(|_, _| (200, Vec::new(), ISSUE_JSON.to_string()));
+    let untrusted = StubServer::start(|_, _| (200, Vec::new(), ISSUE_JSON.to_string()));
+    let dir = std::env::temp_dir().join(format!("sentry-cli-local-token-{}", std::process::id()));
+    let project = dir.join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::create_dir_all(dir.join("config/sentry-cli")).unwrap();
+    std::fs::write(
+        dir.join("config/sentry-cli/config.toml"),
+        format!("server_url = \"{}\"\n", trusted.url),
+    )
+    .unwrap();
+    std::fs::write(
+        project.join(".sentryclirc"),
+        format!(
+            "[auth]\ntoken = dummy\n\n[defaults]\nurl = {}\n",
+            untrusted.url
+        ),
+    )
+    .unwrap();
+
+    // The file's token is not the one in use, so neither is its server
+    sentry_cli()
+        .current_dir(&project)
+        .env("XDG_CONFIG_HOME", dir.join("config"))
+        .env("SENTRY_AUTH_TOKEN", "sntrys_from_ci")
+        .env_remove("SENTRY_PROFILE")
+        .env_remove("SENTRY_SERVER_URL")
+        .args(["--org", "acme", "issues", "view", "1", "-O", "json"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("ignoring server_url"));
+    assert!(untrusted.requests().is_empty());
+    assert_eq!(trusted.requests().len(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_get_unset_list() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-keys-{}", std::process::id()));
+    let cli = || {
+        let mut cmd = sentry_cli();
+        cmd.env("XDG_CONFIG_HOME", &dir)
+            .env_remove("SENTRY_PROFILE")
+            .env_remove("SENTRY_ORG");
+        cmd
+    };
+
+    cli()
+        .args(["config", "set", "default_org", "Not A Slug"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid organization slug"));
+
+    cli()
+        .args(["config", "set", "server_url", "ftp://example.com"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid server_url"));
+
+    cli()
+        .args(["config", "set", "default_org", "acme"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["config", "set", "default_project", "web,Bad Slug"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid project slug: Bad Slug"));
+
+    cli()
+        .args(["config", "set", "default_project", "web, api"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["config", "set", "issue_fields", "id,bogus"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown issue field: bogus"));
+
+    cli()
+        .args(["config", "set", "issue_fields", "shortId,title,assignee"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["config", "get", "default_org"])
+        .assert()
+        .success()
+        .stdout("acme\n");
+
+    cli()
+        .args(["config", "list", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"key\": \"default_org\""));
+
+    cli()
+        .args(["config", "unset", "default_org"])
+        .assert()
+        .success();
+
+    cli()
+        .args(["config", "get", "default_org"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("default_org is not set"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_config_malformed() {
+    let dir = std::env::temp_dir().join(format!("sentry-cli-malformed-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("sentry-cli")).unwrap();
+    std::fs::write(
+        dir.join("sentry-cli/config.toml"),
+        "default_org = \"acme\"\nmax_retries = \"many\"\n",
+    )
+    .unwrap();
+
+    sentry_cli()
+        .env("XDG_CONFIG_HOME", &dir)
+        .env_remove("SENTRY_PROFILE")
+        .args(["config", "show"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("line 2"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}